@@ -51,6 +51,7 @@
 
 use crate::get_error;
 use crate::iostream::IOStream;
+use crate::result_from_bool;
 use crate::sys;
 use crate::AudioSubsystem;
 use crate::Error;
@@ -186,6 +187,11 @@ impl AudioSubsystem {
         device.open_recording_stream_with_callback(spec, callback)
     }
 
+    /// Returns the name of the audio driver in use, such as `"pipewire"`.
+    ///
+    /// This is `"dummy"` when the dummy driver was selected (for example
+    /// through the `SDL_AUDIO_DRIVER` hint). The names match the
+    /// ones yielded by [`drivers`].
     #[doc(alias = "SDL_GetCurrentAudioDriver")]
     pub fn current_audio_driver(&self) -> &'static str {
         unsafe {
@@ -338,6 +344,25 @@ impl AudioFormat {
     fn to_ll(self) -> sys::audio::SDL_AudioFormat {
         self.into()
     }
+
+    /// Returns the size of a single sample of this format, in bytes.
+    ///
+    /// Returns 0 for [`AudioFormat::UNKNOWN`].
+    #[doc(alias = "SDL_AUDIO_BYTESIZE")]
+    pub const fn bytes_per_sample(self) -> usize {
+        match self {
+            AudioFormat::UNKNOWN => 0,
+            AudioFormat::U8 | AudioFormat::S8 => 1,
+            AudioFormat::S16LE | AudioFormat::S16BE => 2,
+            AudioFormat::S32LE | AudioFormat::S32BE | AudioFormat::F32LE | AudioFormat::F32BE => 4,
+        }
+    }
+
+    /// Returns the size of one frame (one sample for each of `channels`), in bytes.
+    #[doc(alias = "SDL_AUDIO_FRAMESIZE")]
+    pub const fn frame_size(self, channels: usize) -> usize {
+        self.bytes_per_sample() * channels
+    }
 }
 
 impl From<AudioFormat> for sys::audio::SDL_AudioFormat {
@@ -380,17 +405,17 @@ impl AudioFormat {
     /// Signed 16-bit samples, native endian
     #[inline]
     pub const fn s16_sys() -> AudioFormat {
-        AudioFormat::S16MSB
+        AudioFormat::S16BE
     }
     /// Signed 32-bit samples, native endian
     #[inline]
     pub const fn s32_sys() -> AudioFormat {
-        AudioFormat::S32MSB
+        AudioFormat::S32BE
     }
     /// 32-bit floating point samples, native endian
     #[inline]
     pub const fn f32_sys() -> AudioFormat {
-        AudioFormat::F32MSB
+        AudioFormat::F32BE
     }
 }
 
@@ -428,12 +453,15 @@ impl Iterator for DriverIterator {
 
 impl ExactSizeIterator for DriverIterator {}
 
-/// Gets an iterator of all audio drivers compiled into the SDL2 library.
+/// Gets an iterator of all audio drivers compiled into the SDL library.
+///
+/// Not every listed driver is necessarily usable at runtime (e.g. `"pipewire"`
+/// without a running server).
 #[doc(alias = "SDL_GetAudioDriver")]
 #[inline]
 pub fn drivers() -> DriverIterator {
     // This function is thread-safe and doesn't require the audio subsystem to be initialized.
-    // The list of drivers are read-only and statically compiled into SDL2, varying by platform.
+    // The list of drivers are read-only and statically compiled into SDL, varying by platform.
 
     // SDL_GetNumAudioDrivers can never return a negative value.
     DriverIterator {
@@ -442,6 +470,27 @@ pub fn drivers() -> DriverIterator {
     }
 }
 
+/// Loads a WAVE from the data source, returning its format and an owned copy
+/// of the samples.
+///
+/// The SDL-allocated buffer is freed before returning. Use
+/// [`AudioSpecWAV::load_wav_io`] to avoid the copy.
+#[doc(alias = "SDL_LoadWAV_IO")]
+pub fn load_wav_io(src: &mut IOStream) -> Result<(AudioSpec, Vec<u8>), Error> {
+    let wav = AudioSpecWAV::load_wav_io(src)?;
+    Ok((wav.spec(), wav.buffer().to_vec()))
+}
+
+/// Copies an SDL-allocated channel map into a `Vec` and frees it.
+unsafe fn channel_map_from_ll(map: *mut c_int, count: c_int) -> Option<Vec<i32>> {
+    if map.is_null() {
+        return None;
+    }
+    let vec = std::slice::from_raw_parts(map, count as usize).to_vec();
+    SDL_free(map as *mut c_void);
+    Some(vec)
+}
+
 pub struct AudioSpecWAV {
     pub freq: i32,
     pub format: AudioFormat,
@@ -454,12 +503,14 @@ impl AudioSpecWAV {
     /// Loads a WAVE from the file path.
     pub fn load_wav<P: AsRef<Path>>(path: P) -> Result<AudioSpecWAV, Error> {
         let mut file = IOStream::from_file(path, "rb")?;
-        AudioSpecWAV::load_wav_rw(&mut file)
+        AudioSpecWAV::load_wav_io(&mut file)
     }
 
     /// Loads a WAVE from the data source.
-    #[doc(alias = "SDL_LoadWAV_RW")]
-    pub fn load_wav_rw(src: &mut IOStream) -> Result<AudioSpecWAV, Error> {
+    ///
+    /// The stream is read from its current position and is not closed.
+    #[doc(alias = "SDL_LoadWAV_IO")]
+    pub fn load_wav_io(src: &mut IOStream) -> Result<AudioSpecWAV, Error> {
         use std::mem::MaybeUninit;
         use std::ptr::null_mut;
 
@@ -489,6 +540,20 @@ impl AudioSpecWAV {
         }
     }
 
+    /// Same as [`AudioSpecWAV::load_wav_io`].
+    pub fn load_wav_rw(src: &mut IOStream) -> Result<AudioSpecWAV, Error> {
+        AudioSpecWAV::load_wav_io(src)
+    }
+
+    /// Returns the stream format of the loaded WAVE.
+    pub fn spec(&self) -> AudioSpec {
+        AudioSpec {
+            freq: Some(self.freq),
+            channels: Some(self.channels as i32),
+            format: Some(self.format),
+        }
+    }
+
     pub fn buffer(&self) -> &[u8] {
         use std::slice::from_raw_parts;
         unsafe {
@@ -666,6 +731,85 @@ impl AudioSpec {
     // }
 }
 
+impl AudioSpec {
+    /// Returns a builder for an `AudioSpec` with concrete values, starting
+    /// from 44100 Hz, 2 channels and native-endian 32-bit float samples.
+    ///
+    /// To get the spec of a loaded WAV file, use [`AudioSpecWAV::spec`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl3::audio::{AudioFormat, AudioSpec};
+    ///
+    /// let spec = AudioSpec::builder()
+    ///     .freq(48000)
+    ///     .channels(1)
+    ///     .format(AudioFormat::s16_sys())
+    ///     .build();
+    /// assert_eq!(spec.frame_size(), Some(2));
+    /// ```
+    pub fn builder() -> AudioSpecBuilder {
+        AudioSpecBuilder::new()
+    }
+
+    /// Returns the size of one audio frame in bytes, or `None` if the
+    /// channel count or format is left to the device default.
+    #[doc(alias = "SDL_AUDIO_FRAMESIZE")]
+    pub fn frame_size(&self) -> Option<usize> {
+        let channels = usize::try_from(self.channels?).ok()?;
+        Some(self.format?.frame_size(channels))
+    }
+}
+
+/// The type that allows you to build an [`AudioSpec`] with concrete values.
+#[derive(Clone, Debug)]
+pub struct AudioSpecBuilder {
+    freq: i32,
+    channels: i32,
+    format: AudioFormat,
+}
+
+impl AudioSpecBuilder {
+    /// Initializes a new `AudioSpecBuilder` with 44100 Hz, 2 channels and
+    /// native-endian 32-bit float samples.
+    pub fn new() -> AudioSpecBuilder {
+        AudioSpecBuilder {
+            freq: 44100,
+            channels: 2,
+            format: AudioFormat::f32_sys(),
+        }
+    }
+
+    /// Sets the sample rate, in Hz.
+    pub fn freq(&mut self, freq: i32) -> &mut AudioSpecBuilder {
+        self.freq = freq;
+        self
+    }
+
+    /// Sets the number of channels.
+    pub fn channels(&mut self, channels: i32) -> &mut AudioSpecBuilder {
+        self.channels = channels;
+        self
+    }
+
+    /// Sets the sample format.
+    pub fn format(&mut self, format: AudioFormat) -> &mut AudioSpecBuilder {
+        self.format = format;
+        self
+    }
+
+    /// Builds the `AudioSpec`.
+    pub fn build(&self) -> AudioSpec {
+        AudioSpec::new(Some(self.freq), Some(self.channels), Some(self.format))
+    }
+}
+
+impl Default for AudioSpecBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Default for AudioSpec {
     /// Creates an `AudioSpec` with all fields set to `None` (use device defaults).
     fn default() -> Self {
@@ -757,6 +901,43 @@ impl AudioDevice {
         }
     }
 
+    /// Get the gain of the audio device.
+    ///
+    /// Device gain is applied after all bound streams have been mixed.
+    #[doc(alias = "SDL_GetAudioDeviceGain")]
+    pub fn get_gain(&self) -> Result<f32, Error> {
+        let gain = unsafe { sys::audio::SDL_GetAudioDeviceGain(self.device_id.id()) };
+        if gain >= 0.0 {
+            Ok(gain)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Set the gain of the audio device.
+    ///
+    /// The final mix is multiplied by `gain`, on top of any per-stream gain:
+    /// 1.0 leaves it unchanged, 0.0 silences the device. Negative values are
+    /// rejected by SDL. Only logical devices (opened by this program) can have
+    /// their gain changed.
+    #[doc(alias = "SDL_SetAudioDeviceGain")]
+    pub fn set_gain(&self, gain: f32) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::audio::SDL_SetAudioDeviceGain(self.device_id.id(), gain) })
+    }
+
+    /// Get the channel map of the audio device, or `None` if the device uses
+    /// the default channel order.
+    ///
+    /// SDL3 does not allow changing a device's channel map; remap the streams
+    /// bound to it instead, see [`AudioStream::set_input_channel_map`].
+    #[doc(alias = "SDL_GetAudioDeviceChannelMap")]
+    pub fn get_channel_map(&self) -> Option<Vec<i32>> {
+        let mut count = 0;
+        let map =
+            unsafe { sys::audio::SDL_GetAudioDeviceChannelMap(self.device_id.id(), &mut count) };
+        unsafe { channel_map_from_ll(map, count) }
+    }
+
     /// Create an `AudioStream` for this device with the specified spec.
     /// This device will be closed when the stream is dropped.
     /// The device begins paused, so you must call `stream.resume()` to start playback.
@@ -1164,6 +1345,96 @@ impl AudioStream {
         }
     }
 
+    /// Sets the gain of the audio stream.
+    ///
+    /// The output is multiplied by `gain`: 1.0 leaves it unchanged, values
+    /// above 1.0 amplify it. Negative values are rejected by SDL. The gain is
+    /// applied when audio is pulled from the stream, so it also affects data
+    /// that is already queued, and can be changed continuously for fades.
+    #[doc(alias = "SDL_SetAudioStreamGain")]
+    pub fn set_gain(&self, gain: f32) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::audio::SDL_SetAudioStreamGain(self.stream, gain) })
+    }
+
+    /// Retrieves the frequency ratio of the audio stream.
+    #[doc(alias = "SDL_GetAudioStreamFrequencyRatio")]
+    pub fn get_frequency_ratio(&self) -> Result<f32, Error> {
+        let ratio = unsafe { sys::audio::SDL_GetAudioStreamFrequencyRatio(self.stream) };
+        if ratio > 0.0 {
+            Ok(ratio)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Sets the frequency ratio of the audio stream, changing playback speed
+    /// and pitch by resampling.
+    ///
+    /// 1.0 is normal speed; SDL rejects values outside of `0.01..=100.0`.
+    /// Like the gain, the ratio is applied when audio is pulled from the
+    /// stream, so it also affects data that is already queued.
+    #[doc(alias = "SDL_SetAudioStreamFrequencyRatio")]
+    pub fn set_frequency_ratio(&self, ratio: f32) -> Result<(), Error> {
+        result_from_bool(unsafe {
+            sys::audio::SDL_SetAudioStreamFrequencyRatio(self.stream, ratio)
+        })
+    }
+
+    /// Get the channel map of the input side of the stream, or `None` if the
+    /// default channel order is used.
+    #[doc(alias = "SDL_GetAudioStreamInputChannelMap")]
+    pub fn get_input_channel_map(&self) -> Option<Vec<i32>> {
+        let mut count = 0;
+        let map = unsafe { sys::audio::SDL_GetAudioStreamInputChannelMap(self.stream, &mut count) };
+        unsafe { channel_map_from_ll(map, count) }
+    }
+
+    /// Get the channel map of the output side of the stream, or `None` if the
+    /// default channel order is used.
+    #[doc(alias = "SDL_GetAudioStreamOutputChannelMap")]
+    pub fn get_output_channel_map(&self) -> Option<Vec<i32>> {
+        let mut count = 0;
+        let map =
+            unsafe { sys::audio::SDL_GetAudioStreamOutputChannelMap(self.stream, &mut count) };
+        unsafe { channel_map_from_ll(map, count) }
+    }
+
+    /// Set the channel map of the input side of the stream.
+    ///
+    /// `map[i]` is the source channel that ends up in channel `i`. The length
+    /// of `map` must match the channel count of the stream's input format,
+    /// and every entry must be a valid channel index (or -1 for silence).
+    /// For a stereo stream, `[1, 0]` swaps left and right and `[0, 0]` plays
+    /// the left channel on both speakers. `None` restores the default order.
+    ///
+    /// The map applies to data put into the stream after this call.
+    #[doc(alias = "SDL_SetAudioStreamInputChannelMap")]
+    pub fn set_input_channel_map(&self, map: Option<&[i32]>) -> Result<(), Error> {
+        let (ptr, count) = match map {
+            Some(map) => (map.as_ptr(), map.len() as i32),
+            None => (std::ptr::null(), 0),
+        };
+        result_from_bool(unsafe {
+            sys::audio::SDL_SetAudioStreamInputChannelMap(self.stream, ptr, count)
+        })
+    }
+
+    /// Set the channel map of the output side of the stream.
+    ///
+    /// Works like [`AudioStream::set_input_channel_map`], but the length of
+    /// `map` must match the channel count of the stream's output format. The
+    /// output map of a stream bound to a device cannot be changed.
+    #[doc(alias = "SDL_SetAudioStreamOutputChannelMap")]
+    pub fn set_output_channel_map(&self, map: Option<&[i32]>) -> Result<(), Error> {
+        let (ptr, count) = match map {
+            Some(map) => (map.as_ptr(), map.len() as i32),
+            None => (std::ptr::null(), 0),
+        };
+        result_from_bool(unsafe {
+            sys::audio::SDL_SetAudioStreamOutputChannelMap(self.stream, ptr, count)
+        })
+    }
+
     /// Pauses playback of the audio stream.
     #[doc(alias = "SDL_PauseAudioStream")]
     pub fn pause(&self) -> Result<(), Error> {
@@ -1361,6 +1632,114 @@ impl Read for AudioStream {
     }
 }
 
+/// Identifies a stream added to an [`AudioMixer`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StreamId(usize);
+
+/// Owns an opened playback device and a set of streams bound to it.
+///
+/// SDL mixes all bound streams together; each stream has its own gain, so
+/// groups of streams can be used as "buses", e.g. to duck music while
+/// dialogue plays:
+///
+/// ```no_run
+/// use sdl3::audio::{AudioMixer, AudioSpec};
+///
+/// let sdl_context = sdl3::init().unwrap();
+/// let audio_subsystem = sdl_context.audio().unwrap();
+/// let spec = AudioSpec::builder().build();
+/// let device = audio_subsystem.open_playback_device(&spec).unwrap();
+///
+/// let mut mixer = AudioMixer::new(device);
+/// let music = mixer.add_stream(&spec).unwrap();
+/// let dialogue = mixer.add_stream(&spec).unwrap();
+///
+/// // while `dialogue` is playing:
+/// mixer.set_group_gain(&[music], 0.3).unwrap();
+/// ```
+pub struct AudioMixer {
+    // declared before `device` so the streams are destroyed before it is closed
+    streams: Vec<Option<AudioStreamOwner>>,
+    device: AudioDevice,
+}
+
+impl AudioMixer {
+    /// Creates a mixer without any streams for an opened device, see
+    /// [`AudioSubsystem::open_playback_device`].
+    pub fn new(device: AudioDevice) -> AudioMixer {
+        AudioMixer {
+            streams: Vec::new(),
+            device,
+        }
+    }
+
+    /// Returns the device the streams are bound to.
+    pub fn device(&self) -> &AudioDevice {
+        &self.device
+    }
+
+    /// Creates a stream taking audio data in `spec` format and binds it to the device.
+    ///
+    /// The data is converted to the device format by SDL.
+    #[doc(alias = "SDL_BindAudioStream")]
+    pub fn add_stream(&mut self, spec: &AudioSpec) -> Result<StreamId, Error> {
+        let stream = self
+            .device
+            .audio_subsystem
+            .new_stream(Some(spec), Some(spec))?;
+        self.device.bind_stream(&stream)?;
+        self.streams.push(Some(stream));
+        Ok(StreamId(self.streams.len() - 1))
+    }
+
+    /// Unbinds the stream from the device and returns it.
+    ///
+    /// Returns `None` if the stream was already removed.
+    #[doc(alias = "SDL_UnbindAudioStream")]
+    pub fn remove_stream(&mut self, id: StreamId) -> Option<AudioStreamOwner> {
+        let stream = self.streams.get_mut(id.0)?.take()?;
+        unsafe { sys::audio::SDL_UnbindAudioStream(stream.stream) };
+        Some(stream)
+    }
+
+    /// Returns the stream for `id`, or `None` if it was removed.
+    pub fn stream(&self, id: StreamId) -> Option<&AudioStream> {
+        self.streams.get(id.0)?.as_deref()
+    }
+
+    fn get(&self, id: StreamId) -> Result<&AudioStream, Error> {
+        self.stream(id)
+            .ok_or_else(|| Error(format!("Invalid stream id: {}", id.0)))
+    }
+
+    /// Queues audio data on a stream, in the format it was added with.
+    pub fn put_data(&self, id: StreamId, buf: &[u8]) -> Result<(), Error> {
+        self.get(id)?.put_data(buf)
+    }
+
+    /// Queues 16-bit signed samples on a stream.
+    pub fn put_data_i16(&self, id: StreamId, buf: &[i16]) -> Result<(), Error> {
+        self.get(id)?.put_data_i16(buf)
+    }
+
+    /// Queues 32-bit float samples on a stream.
+    pub fn put_data_f32(&self, id: StreamId, buf: &[f32]) -> Result<(), Error> {
+        self.get(id)?.put_data_f32(buf)
+    }
+
+    /// Sets the gain of a single stream, see [`AudioStream::set_gain`].
+    pub fn set_gain(&self, id: StreamId, gain: f32) -> Result<(), Error> {
+        self.get(id)?.set_gain(gain)
+    }
+
+    /// Sets the same gain on all `streams`.
+    ///
+    /// Stops at the first stream that fails, leaving the following ones unchanged.
+    pub fn set_group_gain(&self, streams: &[StreamId], gain: f32) -> Result<(), Error> {
+        streams.iter().try_for_each(|&id| self.set_gain(id, gain))
+    }
+}
+
 // Streams with callbacks
 pub struct AudioStreamWithCallback<CB> {
     base_stream: AudioStreamOwner,
@@ -1474,4 +1853,24 @@ impl<'a, CB> Drop for AudioStreamLockGuard<'a, CB> {
 }
 
 #[cfg(test)]
-mod test {}
+mod test {
+    use super::{AudioFormat, AudioSpec};
+
+    #[test]
+    fn test_audio_spec_builder() {
+        let spec = AudioSpec::builder().build();
+        assert_eq!(spec.freq, Some(44100));
+        assert_eq!(spec.channels, Some(2));
+        assert_eq!(spec.format, Some(AudioFormat::f32_sys()));
+        assert_eq!(spec.frame_size(), Some(8));
+
+        let spec = AudioSpec::builder()
+            .freq(22050)
+            .channels(1)
+            .format(AudioFormat::U8)
+            .build();
+        assert_eq!(spec.freq, Some(22050));
+        assert_eq!(spec.frame_size(), Some(1));
+        assert_eq!(AudioSpec::default().frame_size(), None);
+    }
+}
@@ -63,4 +63,67 @@ impl ClipboardUtil {
     pub fn has_clipboard_text(&self) -> bool {
         unsafe { sys::clipboard::SDL_HasClipboardText() }
     }
+
+    /// Lists the MIME types currently offered by the clipboard.
+    ///
+    /// ```no_run
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let clipboard = sdl_context.video().unwrap().clipboard();
+    ///
+    /// let mime_types = clipboard.mime_types().unwrap();
+    /// if mime_types.iter().any(|t| t == "image/png") {
+    ///     let png = clipboard.clipboard_data("image/png").unwrap();
+    ///     // ...
+    /// }
+    /// ```
+    #[doc(alias = "SDL_GetClipboardMimeTypes")]
+    pub fn mime_types(&self) -> Result<Vec<String>, Error> {
+        unsafe {
+            let mut count = 0;
+            let list = sys::clipboard::SDL_GetClipboardMimeTypes(&mut count);
+
+            if list.is_null() {
+                Err(get_error())
+            } else {
+                let mime_types = (0..count)
+                    .map(|i| {
+                        CStr::from_ptr(*list.add(i) as *const _)
+                            .to_string_lossy()
+                            .into_owned()
+                    })
+                    .collect();
+                // The array and its strings are a single allocation.
+                sys::stdinc::SDL_free(list as *mut c_void);
+                Ok(mime_types)
+            }
+        }
+    }
+
+    /// Returns `true` if the clipboard has data for `mime_type`.
+    #[doc(alias = "SDL_HasClipboardData")]
+    pub fn has_clipboard_data(&self, mime_type: &str) -> bool {
+        let mime_type = CString::new(mime_type).unwrap();
+        unsafe { sys::clipboard::SDL_HasClipboardData(mime_type.as_ptr() as *const c_char) }
+    }
+
+    /// Gets the clipboard contents for the given MIME type.
+    #[doc(alias = "SDL_GetClipboardData")]
+    pub fn clipboard_data(&self, mime_type: &str) -> Result<Vec<u8>, Error> {
+        let mime_type = CString::new(mime_type).unwrap();
+        unsafe {
+            let mut size = 0;
+            let buf = sys::clipboard::SDL_GetClipboardData(
+                mime_type.as_ptr() as *const c_char,
+                &mut size,
+            );
+
+            if buf.is_null() {
+                Err(get_error())
+            } else {
+                let data = std::slice::from_raw_parts(buf as *const u8, size).to_vec();
+                sys::stdinc::SDL_free(buf);
+                Ok(data)
+            }
+        }
+    }
 }
@@ -1,13 +1,33 @@
+//! CPU feature detection and system information
+//!
+//! The `has_*` predicates report what the CPU *and* operating system support at
+//! runtime, so they can be used to pick a SIMD code path:
+//!
+//! ```no_run
+//! if sdl3::cpuinfo::has_avx2() {
+//!     // use the AVX2 implementation
+//! } else {
+//!     // scalar fallback
+//! }
+//! ```
+//!
+//! These functions don't require SDL to be initialized.
+
 use crate::sys;
 
 pub const CACHELINESIZE: u8 = 128;
 
+/// Returns the number of logical CPU cores available, which may be more than
+/// the number of physical cores with hyperthreading.
 #[doc(alias = "SDL_GetNumLogicalCPUCores")]
+#[doc(alias = "cpu_count")]
 pub fn num_logical_cpu_cores() -> i32 {
     unsafe { sys::cpuinfo::SDL_GetNumLogicalCPUCores() }
 }
 
+/// Returns the L1 cache line size of the CPU, in bytes.
 #[doc(alias = "SDL_GetCPUCacheLineSize")]
+#[doc(alias = "cache_line_size")]
 pub fn cpu_cache_line_size() -> i32 {
     unsafe { sys::cpuinfo::SDL_GetCPUCacheLineSize() }
 }
@@ -82,11 +102,14 @@ pub fn has_lasx() -> bool {
     unsafe { sys::cpuinfo::SDL_HasLASX() }
 }
 
+/// Returns the amount of RAM configured in the system, in MiB.
 #[doc(alias = "SDL_GetSystemRAM")]
+#[doc(alias = "system_ram_mb")]
 pub fn system_ram() -> i32 {
     unsafe { sys::cpuinfo::SDL_GetSystemRAM() }
 }
 
+/// Returns the alignment, in bytes, needed for SIMD allocations on this system.
 #[doc(alias = "SDL_GetSIMDAlignment")]
 pub fn simd_alignment() -> usize {
     unsafe { sys::cpuinfo::SDL_GetSIMDAlignment() }
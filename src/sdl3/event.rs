@@ -18,6 +18,7 @@ use crate::gamepad::{Axis, Button};
 use crate::get_error;
 use crate::joystick;
 use crate::joystick::HatState;
+use crate::joystick::JoystickId;
 use crate::keyboard;
 use crate::keyboard::Keycode;
 use crate::keyboard::Mod;
@@ -65,6 +66,22 @@ impl crate::EventSubsystem {
         unsafe { sys::events::SDL_FlushEvent(event_type.into()) };
     }
 
+    /// Enables or disables processing of the specified event type.
+    ///
+    /// Disabled events are dropped before they are queued, so event types that are
+    /// never handled (e.g. `EventType::FingerMotion` in a desktop game) cost nothing.
+    /// Disabling an event type also removes the matching events already in the queue.
+    #[doc(alias = "SDL_SetEventEnabled")]
+    pub fn set_event_enabled(&self, event_type: EventType, enabled: bool) {
+        unsafe { sys::events::SDL_SetEventEnabled(event_type.into(), enabled) };
+    }
+
+    /// Returns `true` if the specified event type is processed and queued.
+    #[doc(alias = "SDL_EventEnabled")]
+    pub fn event_enabled(&self, event_type: EventType) -> bool {
+        unsafe { sys::events::SDL_EventEnabled(event_type.into()) }
+    }
+
     /// Removes all events in the event queue that match the specified type range.
     #[doc(alias = "SDL_FlushEvents")]
     pub fn flush_events(&self, min_type: u32, max_type: u32) {
@@ -306,6 +323,7 @@ pub enum EventType {
     WindowHitTest = sys::events::SDL_EVENT_WINDOW_HIT_TEST.0,
     WindowICCProfileChanged = sys::events::SDL_EVENT_WINDOW_ICCPROF_CHANGED.0,
     WindowDisplayChanged = sys::events::SDL_EVENT_WINDOW_DISPLAY_CHANGED.0,
+    WindowOccluded = sys::events::SDL_EVENT_WINDOW_OCCLUDED.0,
 
     // TODO: SysWM = sys::events::SDL_EVENT_SYSWM .0,
     KeyDown = sys::events::SDL_EVENT_KEY_DOWN.0,
@@ -408,6 +426,10 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_WINDOW_FOCUS_GAINED => WindowFocusGained,
             SDL_EVENT_WINDOW_FOCUS_LOST => WindowFocusLost,
             SDL_EVENT_WINDOW_CLOSE_REQUESTED => WindowCloseRequested,
+            SDL_EVENT_WINDOW_HIT_TEST => WindowHitTest,
+            SDL_EVENT_WINDOW_ICCPROF_CHANGED => WindowICCProfileChanged,
+            SDL_EVENT_WINDOW_DISPLAY_CHANGED => WindowDisplayChanged,
+            SDL_EVENT_WINDOW_OCCLUDED => WindowOccluded,
 
             SDL_EVENT_KEY_DOWN => KeyDown,
             SDL_EVENT_KEY_UP => KeyUp,
@@ -542,9 +564,16 @@ pub enum WindowEvent {
     None,
     Shown,
     Hidden,
+    /// The window needs to be redrawn.
     Exposed,
+    /// The window was moved to `(x, y)`.
     Moved(i32, i32),
+    /// The window was resized to `(width, height)`, in window coordinates.
     Resized(i32, i32),
+    /// The size of the window in pixels changed to `(width, height)`.
+    ///
+    /// On high density displays this differs from [`WindowEvent::Resized`],
+    /// and it is also sent when only the display scale changes.
     PixelSizeChanged(i32, i32),
     Minimized,
     Maximized,
@@ -556,7 +585,10 @@ pub enum WindowEvent {
     CloseRequested,
     HitTest(i32, i32),
     ICCProfChanged,
+    /// The window moved to the display with the given id.
     DisplayChanged(i32),
+    /// The window is fully covered by other windows, so rendering can be skipped.
+    Occluded,
 }
 
 impl WindowEvent {
@@ -581,6 +613,7 @@ impl WindowEvent {
                 EventType::WindowHitTest => WindowEvent::HitTest(data1, data2),
                 EventType::WindowICCProfileChanged => WindowEvent::ICCProfChanged,
                 EventType::WindowDisplayChanged => WindowEvent::DisplayChanged(data1),
+                EventType::WindowOccluded => WindowEvent::Occluded,
                 _ => WindowEvent::None,
             },
             Err(_) => WindowEvent::None,
@@ -607,6 +640,7 @@ impl WindowEvent {
             WindowEvent::HitTest(d1, d2) => (EventType::WindowHitTest, d1, d2),
             WindowEvent::ICCProfChanged => (EventType::WindowICCProfileChanged, 0, 0),
             WindowEvent::DisplayChanged(d1) => (EventType::WindowDisplayChanged, d1, 0),
+            WindowEvent::Occluded => (EventType::WindowOccluded, 0, 0),
         }
     }
 
@@ -631,6 +665,7 @@ impl WindowEvent {
                 | (Self::HitTest(_, _), Self::HitTest(_, _))
                 | (Self::ICCProfChanged, Self::ICCProfChanged)
                 | (Self::DisplayChanged(_), Self::DisplayChanged(_))
+                | (Self::Occluded, Self::Occluded)
         )
     }
 }
@@ -667,24 +702,37 @@ pub enum Event {
     },
 
     // TODO: SysWMEvent
+    /// A key was pressed.
     KeyDown {
         timestamp: u64,
         window_id: u32,
+        /// The virtual key, which depends on the keyboard layout.
         keycode: Option<Keycode>,
+        /// The physical key position, independent of the keyboard layout.
         scancode: Option<Scancode>,
         keymod: Mod,
+        /// `true` if this is a key repeat generated by holding the key down,
+        /// `false` for the initial press.
         repeat: bool,
+        /// The keyboard instance id, or 0 if unknown or virtual.
         which: u32,
+        /// The platform dependent scancode reported by the hardware.
         raw: Uint16,
     },
+    /// A key was released.
     KeyUp {
         timestamp: u64,
         window_id: u32,
+        /// The virtual key, which depends on the keyboard layout.
         keycode: Option<Keycode>,
+        /// The physical key position, independent of the keyboard layout.
         scancode: Option<Scancode>,
         keymod: Mod,
+        /// Only set on key presses, so always `false` for a release.
         repeat: bool,
+        /// The keyboard instance id, or 0 if unknown or virtual.
         which: u32,
+        /// The platform dependent scancode reported by the hardware.
         raw: Uint16,
     },
 
@@ -772,15 +820,19 @@ pub enum Event {
         button_idx: u8,
     },
 
+    /// A joystick was connected.
+    ///
+    /// `which` can be passed directly to [`JoystickSubsystem::open`](crate::JoystickSubsystem::open).
     JoyDeviceAdded {
         timestamp: u64,
-        /// The newly added joystick's `joystick_index`
-        which: u32,
+        /// The newly added joystick's instance `id`
+        which: JoystickId,
     },
+    /// A joystick was disconnected.
     JoyDeviceRemoved {
         timestamp: u64,
-        /// The joystick's `id`
-        which: u32,
+        /// The joystick's instance `id`
+        which: JoystickId,
     },
 
     ControllerAxisMotion {
@@ -804,20 +856,54 @@ pub enum Event {
         button: Button,
     },
 
+    /// A gamepad was connected.
+    ///
+    /// `which` is the joystick instance id of the new gamepad and can be passed
+    /// directly to [`GamepadSubsystem::open`](crate::GamepadSubsystem::open).
+    /// The gamepad subsystem has to be initialized for these events to be sent.
+    ///
+    /// # Example: open on connect, close on disconnect
+    /// ```no_run
+    /// use sdl3::event::Event;
+    /// use std::collections::HashMap;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let gamepad_subsystem = sdl_context.gamepad().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    /// let mut gamepads = HashMap::new();
+    ///
+    /// for event in event_pump.wait_iter() {
+    ///     match event {
+    ///         Event::ControllerDeviceAdded { which, .. } => {
+    ///             if let Ok(gamepad) = gamepad_subsystem.open(which) {
+    ///                 gamepads.insert(which, gamepad);
+    ///             }
+    ///         }
+    ///         Event::ControllerDeviceRemoved { which, .. } => {
+    ///             // dropping the `Gamepad` closes it
+    ///             gamepads.remove(&which);
+    ///         }
+    ///         Event::Quit { .. } => break,
+    ///         _ => (),
+    ///     }
+    /// }
+    /// ```
     ControllerDeviceAdded {
         timestamp: u64,
-        /// The newly added controller's `joystick_index`
-        which: u32,
+        /// The newly added controller's joystick instance `id`
+        which: JoystickId,
     },
+    /// A gamepad was disconnected.
     ControllerDeviceRemoved {
         timestamp: u64,
-        /// The controller's joystick `id`
-        which: u32,
+        /// The controller's joystick instance `id`
+        which: JoystickId,
     },
+    /// A gamepad's mapping was updated.
     ControllerDeviceRemapped {
         timestamp: u64,
-        /// The controller's joystick `id`
-        which: u32,
+        /// The controller's joystick instance `id`
+        which: JoystickId,
     },
 
     ControllerTouchpadDown {
@@ -1595,7 +1681,8 @@ impl Event {
                 | EventType::WindowCloseRequested
                 | EventType::WindowHitTest
                 | EventType::WindowICCProfileChanged
-                | EventType::WindowDisplayChanged => {
+                | EventType::WindowDisplayChanged
+                | EventType::WindowOccluded => {
                     let event = raw.window;
                     Event::Window {
                         timestamp: event.timestamp,
@@ -1699,12 +1786,13 @@ impl Event {
                 EventType::TextEditing => {
                     let event = raw.edit;
 
-                    // event.text is a *const c_char (pointer to a C string)
-                    let c_str: &CStr = CStr::from_ptr(event.text);
-
-                    // Convert the CStr to a Rust &str
-                    let text_str = c_str.to_str().expect("Invalid UTF-8 string");
-                    let text = text_str.to_owned();
+                    // event.text is a *const c_char (pointer to a C string) owned by SDL,
+                    // so it is copied here
+                    let text = if event.text.is_null() {
+                        String::new()
+                    } else {
+                        CStr::from_ptr(event.text).to_string_lossy().into_owned()
+                    };
 
                     Event::TextEditing {
                         timestamp: event.timestamp,
@@ -1717,12 +1805,13 @@ impl Event {
                 EventType::TextInput => {
                     let event = raw.text;
 
-                    // event.text is a *const c_char (pointer to a C string)
-                    let c_str: &CStr = CStr::from_ptr(event.text);
-
-                    // Convert the CStr to a Rust &str
-                    let text_str = c_str.to_str().expect("Invalid UTF-8 string");
-                    let text = text_str.to_owned();
+                    // event.text is a *const c_char (pointer to a C string) owned by SDL,
+                    // so it is copied here
+                    let text = if event.text.is_null() {
+                        String::new()
+                    } else {
+                        CStr::from_ptr(event.text).to_string_lossy().into_owned()
+                    };
 
                     Event::TextInput {
                         timestamp: event.timestamp,
@@ -2727,6 +2816,37 @@ impl crate::EventPump {
         }
     }
 
+    /// Polls up to `max` currently pending events and returns them in a `Vec`.
+    ///
+    /// Polling stops early once the queue is empty; a `max` of `0` drains the
+    /// whole queue. Unlike `poll_iter()`, the returned events don't borrow the
+    /// `EventPump`, so it can still be used while handling them.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    ///
+    /// for event in event_pump.poll_batch(0) {
+    ///     use sdl3::event::Event;
+    ///     match event {
+    ///         Event::KeyDown {..} => { /*...*/ }
+    ///         _ => ()
+    ///     }
+    /// }
+    /// ```
+    #[doc(alias = "SDL_PollEvent")]
+    pub fn poll_batch(&mut self, max: usize) -> Vec<Event> {
+        let mut events = Vec::new();
+        while max == 0 || events.len() < max {
+            match unsafe { poll_event() } {
+                Some(event) => events.push(event),
+                None => break,
+            }
+        }
+        events
+    }
+
     /// Pumps the event loop, gathering events from the input devices.
     #[doc(alias = "SDL_PumpEvents")]
     pub fn pump_events(&mut self) {
@@ -2862,6 +2982,24 @@ mod test {
             let e2 = Event::from_ll(e.clone().to_ll().unwrap());
             assert_eq!(e, e2);
         }
+        {
+            let e = Event::Window {
+                timestamp: 0,
+                window_id: 3,
+                win_event: WindowEvent::DisplayChanged(2),
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
+        {
+            let e = Event::Window {
+                timestamp: 0,
+                window_id: 3,
+                win_event: WindowEvent::Occluded,
+            };
+            let e2 = Event::from_ll(e.clone().to_ll().unwrap());
+            assert_eq!(e, e2);
+        }
         {
             let e = Event::KeyDown {
                 timestamp: 0,
@@ -3105,9 +3243,33 @@ mod test {
             panic!()
         }
     }
+
+    #[test]
+    fn test_event_sender_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<super::EventSender>();
+    }
 }
 
 /// A sendible type that can push events to the event queue.
+///
+/// `EventSender` is `Send + Sync` and does not borrow the (main-thread bound)
+/// [`EventSubsystem`](crate::EventSubsystem), so it can be moved into worker threads to notify the
+/// main loop. `SDL_PushEvent` is thread-safe.
+///
+/// ```no_run
+/// let sdl = sdl3::init().unwrap();
+/// let ev = sdl.event().unwrap();
+/// let sender = ev.event_sender();
+///
+/// std::thread::spawn(move || {
+///     // ... load assets ...
+///     sender
+///         .push_event(sdl3::event::Event::Quit { timestamp: 0 })
+///         .unwrap();
+/// });
+/// ```
+#[derive(Clone)]
 pub struct EventSender {
     _priv: (),
 }
@@ -6,6 +6,7 @@ use std::fmt;
 use std::io;
 use std::mem::transmute;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 
 #[cfg(feature = "hidapi")]
 use crate::sensor::SensorType;
@@ -56,7 +57,21 @@ impl error::Error for AddMappingError {
 }
 
 impl GamepadSubsystem {
-    /// Retrieve the total number of attached gamepads identified by SDL.
+    /// Retrieve the instance IDs of the currently attached gamepads.
+    ///
+    /// Only joysticks that SDL has a gamepad mapping for are listed, see
+    /// [`GamepadSubsystem::is_gamepad`]. The IDs can be passed to the `*_for_id`
+    /// functions to query a gamepad without opening it:
+    ///
+    /// ```no_run
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let gamepad_subsystem = sdl_context.gamepad().unwrap();
+    ///
+    /// for id in gamepad_subsystem.gamepads().unwrap() {
+    ///     let name = gamepad_subsystem.name_for_id(id).unwrap_or_default();
+    ///     println!("{id}: {name}");
+    /// }
+    /// ```
     #[doc(alias = "SDL_GetGamepads")]
     pub fn gamepads(&self) -> Result<Vec<JoystickId>, Error> {
         let mut num_gamepads: i32 = 0;
@@ -77,7 +92,8 @@ impl GamepadSubsystem {
         }
     }
 
-    /// Return true if the joystick at index `joystick_id` is a game controller.
+    /// Return true if the joystick with instance ID `joystick_id` is supported
+    /// by the gamepad interface, i.e. SDL has a mapping for it.
     #[inline]
     #[doc(alias = "SDL_IsGamepad")]
     pub fn is_gamepad(&self, joystick_id: JoystickId) -> bool {
@@ -138,7 +154,7 @@ impl GamepadSubsystem {
         }
     }
 
-    /// Return the name of the controller at index `joystick_index`.
+    /// Return the name of the gamepad with instance ID `joystick_id`.
     /// This can be called before any gamepads are opened.
     #[doc(alias = "SDL_GetGamepadNameForID")]
     pub fn name_for_id(&self, joystick_id: JoystickId) -> Result<String, IntegerOrSdlError> {
@@ -584,6 +600,7 @@ pub enum Button {
     Misc3 = sys::gamepad::SDL_GAMEPAD_BUTTON_MISC3.0,
     Misc4 = sys::gamepad::SDL_GAMEPAD_BUTTON_MISC4.0,
     Misc5 = sys::gamepad::SDL_GAMEPAD_BUTTON_MISC5.0,
+    Misc6 = sys::gamepad::SDL_GAMEPAD_BUTTON_MISC6.0,
     RightPaddle1 = sys::gamepad::SDL_GAMEPAD_BUTTON_RIGHT_PADDLE1.0,
     LeftPaddle1 = sys::gamepad::SDL_GAMEPAD_BUTTON_LEFT_PADDLE1.0,
     RightPaddle2 = sys::gamepad::SDL_GAMEPAD_BUTTON_RIGHT_PADDLE2.0,
@@ -644,6 +661,7 @@ impl Button {
             sys::gamepad::SDL_GAMEPAD_BUTTON_MISC3 => Button::Misc3,
             sys::gamepad::SDL_GAMEPAD_BUTTON_MISC4 => Button::Misc4,
             sys::gamepad::SDL_GAMEPAD_BUTTON_MISC5 => Button::Misc5,
+            sys::gamepad::SDL_GAMEPAD_BUTTON_MISC6 => Button::Misc6,
             sys::gamepad::SDL_GAMEPAD_BUTTON_LEFT_PADDLE1 => Button::LeftPaddle1,
             sys::gamepad::SDL_GAMEPAD_BUTTON_RIGHT_PADDLE1 => Button::RightPaddle1,
             sys::gamepad::SDL_GAMEPAD_BUTTON_LEFT_PADDLE2 => Button::LeftPaddle2,
@@ -675,6 +693,7 @@ impl Button {
             Button::Misc3 => sys::gamepad::SDL_GAMEPAD_BUTTON_MISC3,
             Button::Misc4 => sys::gamepad::SDL_GAMEPAD_BUTTON_MISC4,
             Button::Misc5 => sys::gamepad::SDL_GAMEPAD_BUTTON_MISC5,
+            Button::Misc6 => sys::gamepad::SDL_GAMEPAD_BUTTON_MISC6,
             Button::LeftPaddle1 => sys::gamepad::SDL_GAMEPAD_BUTTON_LEFT_PADDLE1,
             Button::RightPaddle1 => sys::gamepad::SDL_GAMEPAD_BUTTON_RIGHT_PADDLE1,
             Button::LeftPaddle2 => sys::gamepad::SDL_GAMEPAD_BUTTON_LEFT_PADDLE2,
@@ -769,7 +788,10 @@ impl Gamepad {
         c_str_to_string_or_none(c_str)
     }
 
-    /// Return the type of an opened gamepad.
+    /// Return the type of an opened gamepad (Xbox 360, PS5, Switch Pro, ...).
+    ///
+    /// This takes mapping overrides into account; see [`Gamepad::real_type`] for the type
+    /// reported by the hardware. For button prompts prefer [`Gamepad::label_for_button`].
     #[doc(alias = "SDL_GetGamepadType")]
     pub fn r#type(&self) -> GamepadType {
         let raw_type = unsafe { sys::gamepad::SDL_GetGamepadType(self.raw) };
@@ -950,7 +972,12 @@ impl Gamepad {
         unsafe { sys::gamepad::SDL_GamepadHasAxis(self.raw, raw_axis) }
     }
 
-    /// Get the position of the given `axis`
+    /// Get the current position of the given `axis`.
+    ///
+    /// Stick axes range from -32768 to 32767; triggers range from 0 to 32767.
+    /// No deadzone is applied, so values near zero should be filtered by the
+    /// caller. This reads the state as of the last event pump update and can
+    /// be used to poll input instead of handling axis motion events.
     #[doc(alias = "SDL_GetGamepadAxis")]
     pub fn axis(&self, axis: Axis) -> i16 {
         // This interface is a bit messed up: 0 is a valid position
@@ -977,6 +1004,9 @@ impl Gamepad {
     }
 
     /// Returns `true` if `button` is pressed.
+    ///
+    /// This reads the state as of the last event pump update and can be used
+    /// to poll input instead of handling button events.
     #[doc(alias = "SDL_GetGamepadButton")]
     pub fn button(&self, button: Button) -> bool {
         // This interface is a bit messed up: 0 is a valid position
@@ -992,16 +1022,20 @@ impl Gamepad {
         unsafe { sys::gamepad::SDL_GetGamepadButton(self.raw, raw_button) }
     }
 
-    /// Return the label of a button on this gamepad
+    /// Return the label printed on a button of this gamepad.
+    ///
+    /// Use this to show the right button prompts: for example `Button::South` is labeled
+    /// `A` on Xbox controllers, `Cross` on PlayStation controllers and `B` on Nintendo
+    /// controllers, which swap the east and south buttons.
     #[doc(alias = "SDL_GetGamepadButtonLabel")]
-    pub fn button_label_for_gamepad_type(&self, button: Button) -> ButtonLabel {
-        let raw_button: sys::gamepad::SDL_GamepadButton;
-        unsafe {
-            raw_button = transmute(button);
-        }
+    pub fn label_for_button(&self, button: Button) -> ButtonLabel {
+        let raw = unsafe { sys::gamepad::SDL_GetGamepadButtonLabel(self.raw, button.to_ll()) };
+        ButtonLabel::from_ll(raw).unwrap_or(ButtonLabel::Unknown)
+    }
 
-        let raw = unsafe { sys::gamepad::SDL_GetGamepadButtonLabel(self.raw, raw_button) };
-        unsafe { transmute(raw) }
+    /// Same as [`Gamepad::label_for_button`].
+    pub fn button_label_for_gamepad_type(&self, button: Button) -> ButtonLabel {
+        self.label_for_button(button)
     }
 
     /// Return the number of touchpads on this gamepad
@@ -1050,6 +1084,56 @@ impl Gamepad {
         }
     }
 
+    /// Plays `pattern` on the controller's rumble motors.
+    ///
+    /// The steps are advanced from a timer thread, so the pattern keeps playing without further
+    /// calls. It stops when it is done, or when the returned handle is dropped, which also turns
+    /// the motors off. Starting another rumble on this controller overrides the current step.
+    ///
+    /// The handle does not borrow the controller: if the controller is closed first the pattern
+    /// simply stops having an effect.
+    ///
+    /// The timer frees its state itself on the run after the pattern ends or is cancelled. If SDL
+    /// is shut down before that run, the state (a copy of the steps) is leaked.
+    #[doc(alias = "SDL_AddTimer")]
+    pub fn play_rumble_pattern(
+        &mut self,
+        pattern: &RumblePattern,
+    ) -> Result<RumbleHandle, IntegerOrSdlError> {
+        let id = self.id().map_err(IntegerOrSdlError::SdlError)?;
+        let Some(&(low, high, duration_ms)) = pattern.steps.first() else {
+            return Ok(RumbleHandle {
+                id,
+                state: Arc::new(Mutex::new(RumbleState::Finished)),
+            });
+        };
+        self.set_rumble(low, high, duration_ms)?;
+
+        let state = Arc::new(Mutex::new(RumbleState::Playing));
+        // The timer owns its state and frees it itself once it returns 0, so it is never freed
+        // while the callback is running.
+        let timer = Box::into_raw(Box::new(RumbleTimer {
+            id,
+            steps: pattern.steps.clone(),
+            repeat: pattern.repeat,
+            index: 0,
+            state: state.clone(),
+        }));
+        let timer_id = unsafe {
+            sys::timer::SDL_AddTimer(
+                duration_ms.max(1),
+                Some(rumble_timer_callback),
+                timer as *mut c_void,
+            )
+        };
+        if timer_id == 0 {
+            drop(unsafe { Box::from_raw(timer) });
+            return Err(IntegerOrSdlError::SdlError(get_error()));
+        }
+
+        Ok(RumbleHandle { id, state })
+    }
+
     /// Start a rumble effect in the game controller's triggers.
     #[doc(alias = "SDL_RumbleGamepadTriggers")]
     pub fn set_rumble_triggers(
@@ -1211,6 +1295,148 @@ impl Drop for Gamepad {
     }
 }
 
+/// A sequence of rumble steps played by [`Gamepad::play_rumble_pattern`].
+///
+/// Each step is a `(low_frequency, high_frequency, duration_ms)` triple, using the same intensity
+/// range as [`Gamepad::set_rumble`].
+///
+/// ```no_run
+/// use sdl3::gamepad::RumblePattern;
+///
+/// // A heartbeat: two short pulses and a pause, repeated.
+/// let heartbeat = RumblePattern::new()
+///     .step(0xC000, 0x4000, 80)
+///     .step(0, 0, 80)
+///     .step(0x8000, 0x2000, 80)
+///     .step(0, 0, 500)
+///     .repeat(true);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RumblePattern {
+    steps: Vec<(u16, u16, u32)>,
+    repeat: bool,
+}
+
+impl RumblePattern {
+    /// Creates an empty pattern.
+    pub fn new() -> RumblePattern {
+        RumblePattern::default()
+    }
+
+    /// Appends a step to the pattern.
+    pub fn step(
+        mut self,
+        low_frequency_rumble: u16,
+        high_frequency_rumble: u16,
+        duration_ms: u32,
+    ) -> RumblePattern {
+        self.steps
+            .push((low_frequency_rumble, high_frequency_rumble, duration_ms));
+        self
+    }
+
+    /// Sets whether the pattern starts over after the last step, until its handle is dropped.
+    pub fn repeat(mut self, repeat: bool) -> RumblePattern {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Returns the steps of the pattern.
+    pub fn steps(&self) -> &[(u16, u16, u32)] {
+        &self.steps
+    }
+}
+
+/// A rumble pattern that is playing on a controller.
+///
+/// Dropping the handle cancels the pattern and stops the motors.
+#[must_use = "if unused the rumble pattern will be stopped immediately"]
+pub struct RumbleHandle {
+    id: JoystickId,
+    state: Arc<Mutex<RumbleState>>,
+}
+
+impl RumbleHandle {
+    /// Returns `true` once all steps of a non-repeating pattern have been played.
+    pub fn is_finished(&self) -> bool {
+        *self.state.lock().unwrap() == RumbleState::Finished
+    }
+}
+
+impl Drop for RumbleHandle {
+    fn drop(&mut self) {
+        // Holding the lock while stopping the motors keeps the timer from starting another step
+        // afterwards. The timer is not removed here: it sees the new state on its next run and
+        // frees itself.
+        let mut state = self.state.lock().unwrap();
+        if *state == RumbleState::Playing {
+            unsafe {
+                let raw = sys::gamepad::SDL_GetGamepadFromID(self.id);
+                if !raw.is_null() {
+                    sys::gamepad::SDL_RumbleGamepad(raw, 0, 0, 0);
+                }
+            }
+        }
+        *state = RumbleState::Cancelled;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RumbleState {
+    Playing,
+    Finished,
+    Cancelled,
+}
+
+struct RumbleTimer {
+    id: JoystickId,
+    steps: Vec<(u16, u16, u32)>,
+    repeat: bool,
+    index: usize,
+    state: Arc<Mutex<RumbleState>>,
+}
+
+extern "C" fn rumble_timer_callback(
+    userdata: *mut c_void,
+    _timer_id: sys::timer::SDL_TimerID,
+    _interval: u32,
+) -> u32 {
+    let timer = userdata as *mut RumbleTimer;
+    let next = unsafe { (*timer).advance() };
+    if next == 0 {
+        // Returning 0 removes the timer, so nothing else can reach the state.
+        drop(unsafe { Box::from_raw(timer) });
+    }
+    next
+}
+
+impl RumbleTimer {
+    /// Plays the next step and returns the delay until the one after it, or 0 to stop.
+    fn advance(&mut self) -> u32 {
+        let mut state = self.state.lock().unwrap();
+        if *state != RumbleState::Playing {
+            return 0;
+        }
+        self.index += 1;
+        if self.index == self.steps.len() && self.repeat {
+            self.index = 0;
+        }
+        let (low, high, duration_ms) = self.steps.get(self.index).copied().unwrap_or((0, 0, 0));
+        unsafe {
+            let raw = sys::gamepad::SDL_GetGamepadFromID(self.id);
+            if !raw.is_null() {
+                sys::gamepad::SDL_RumbleGamepad(raw, low, high, duration_ms);
+            }
+        }
+        if self.index < self.steps.len() {
+            duration_ms.max(1)
+        } else {
+            *state = RumbleState::Finished;
+            0
+        }
+    }
+}
+
 /// Convert C string `c_str` to a String. Return an empty string if
 /// `c_str` is NULL.
 fn c_str_to_string(c_str: *const c_char) -> String {
@@ -5,8 +5,9 @@ use crate::{
         GraphicsPipelineBuilder, RenderPass, Sampler, SamplerCreateInfo, ShaderBuilder,
         ShaderFormat, Texture, TextureCreateInfo, TextureFormat, TransferBufferBuilder,
     },
-    sys, Error,
+    result_from_bool, sys, Error,
 };
+use std::marker::PhantomData;
 use std::sync::{Arc, Weak};
 use sys::gpu::{
     SDL_BeginGPUComputePass, SDL_BeginGPUCopyPass, SDL_BeginGPURenderPass, SDL_CreateGPUDevice,
@@ -62,14 +63,28 @@ impl Device {
         }
     }
 
+    /// Claims `w` for this device, creating its swapchain. Consuming
+    /// version of [`Device::claim_window`] for use in builder chains.
     #[doc(alias = "SDL_ClaimWindowForGPUDevice")]
     pub fn with_window(self, w: &crate::video::Window) -> Result<Self, Error> {
-        let p = unsafe { sys::gpu::SDL_ClaimWindowForGPUDevice(self.inner.0, w.raw()) };
-        if p {
-            Ok(self)
-        } else {
-            Err(get_error())
-        }
+        self.claim_window(w)?;
+        Ok(self)
+    }
+
+    /// Claims `w` for this device, creating its swapchain.
+    ///
+    /// This must be called before acquiring swapchain textures for the window.
+    /// A window can only be claimed by one device at a time.
+    #[doc(alias = "SDL_ClaimWindowForGPUDevice")]
+    pub fn claim_window(&self, w: &crate::video::Window) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::gpu::SDL_ClaimWindowForGPUDevice(self.inner.0, w.raw()) })
+    }
+
+    /// Unclaims a window previously claimed with [`Device::claim_window`],
+    /// destroying its swapchain.
+    #[doc(alias = "SDL_ReleaseWindowFromGPUDevice")]
+    pub fn release_window(&self, w: &crate::video::Window) {
+        unsafe { sys::gpu::SDL_ReleaseWindowFromGPUDevice(self.inner.0, w.raw()) }
     }
 
     #[doc(alias = "SDL_AcquireGPUCommandBuffer")]
@@ -135,12 +150,12 @@ impl Device {
 
     // You cannot begin another render pass, or begin a compute pass or copy pass until you have ended the render pass.
     #[doc(alias = "SDL_BeginGPURenderPass")]
-    pub fn begin_render_pass(
+    pub fn begin_render_pass<'a>(
         &self,
-        command_buffer: &CommandBuffer,
+        command_buffer: &'a CommandBuffer,
         color_info: &[ColorTargetInfo],
         depth_stencil_target: Option<&DepthStencilTargetInfo>,
-    ) -> Result<RenderPass, Error> {
+    ) -> Result<RenderPass<'a>, Error> {
         let p = unsafe {
             SDL_BeginGPURenderPass(
                 command_buffer.inner,
@@ -154,7 +169,10 @@ impl Device {
             )
         };
         if !p.is_null() {
-            Ok(RenderPass { inner: p })
+            Ok(RenderPass {
+                inner: p,
+                _marker: PhantomData,
+            })
         } else {
             Err(get_error())
         }
@@ -168,10 +186,16 @@ impl Device {
     }
 
     #[doc(alias = "SDL_BeginGPUCopyPass")]
-    pub fn begin_copy_pass(&self, command_buffer: &CommandBuffer) -> Result<CopyPass, Error> {
+    pub fn begin_copy_pass<'a>(
+        &self,
+        command_buffer: &'a CommandBuffer,
+    ) -> Result<CopyPass<'a>, Error> {
         let p = unsafe { SDL_BeginGPUCopyPass(command_buffer.inner) };
         if !p.is_null() {
-            Ok(CopyPass { inner: p })
+            Ok(CopyPass {
+                inner: p,
+                _marker: PhantomData,
+            })
         } else {
             Err(get_error())
         }
@@ -184,12 +208,12 @@ impl Device {
     }
 
     #[doc(alias = "SDL_BeginGPUComputePass")]
-    pub fn begin_compute_pass(
+    pub fn begin_compute_pass<'a>(
         &self,
-        command_buffer: &CommandBuffer,
+        command_buffer: &'a CommandBuffer,
         storage_texture_bindings: &[StorageTextureReadWriteBinding],
         storage_buffer_bindings: &[StorageBufferReadWriteBinding],
-    ) -> Result<ComputePass, Error> {
+    ) -> Result<ComputePass<'a>, Error> {
         let p = unsafe {
             SDL_BeginGPUComputePass(
                 command_buffer.inner,
@@ -200,7 +224,10 @@ impl Device {
             )
         };
         if !p.is_null() {
-            Ok(ComputePass { inner: p })
+            Ok(ComputePass {
+                inner: p,
+                _marker: PhantomData,
+            })
         } else {
             Err(get_error())
         }
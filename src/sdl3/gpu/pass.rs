@@ -17,6 +17,8 @@ use sys::gpu::{
     SDL_UploadToGPUTexture, SDL_WaitAndAcquireGPUSwapchainTexture,
 };
 
+use std::marker::PhantomData;
+
 use super::{Buffer, ComputePipeline};
 
 pub struct CommandBuffer {
@@ -215,10 +217,12 @@ impl ColorTargetInfo {
     }
 }
 
-pub struct RenderPass {
+/// A pass recorded into a [`CommandBuffer`], which it borrows until it ends.
+pub struct RenderPass<'a> {
     pub(super) inner: *mut SDL_GPURenderPass,
+    pub(super) _marker: PhantomData<&'a CommandBuffer>,
 }
-impl RenderPass {
+impl RenderPass<'_> {
     #[inline]
     pub fn raw(&self) -> *mut SDL_GPURenderPass {
         self.inner
@@ -357,10 +361,12 @@ impl RenderPass {
     }
 }
 
-pub struct CopyPass {
+/// A pass recorded into a [`CommandBuffer`], which it borrows until it ends.
+pub struct CopyPass<'a> {
     pub(super) inner: *mut SDL_GPUCopyPass,
+    pub(super) _marker: PhantomData<&'a CommandBuffer>,
 }
-impl CopyPass {
+impl CopyPass<'_> {
     #[inline]
     pub fn raw(&self) -> *mut SDL_GPUCopyPass {
         self.inner
@@ -394,10 +400,12 @@ impl CopyPass {
     }
 }
 
-pub struct ComputePass {
+/// A pass recorded into a [`CommandBuffer`], which it borrows until it ends.
+pub struct ComputePass<'a> {
     pub(super) inner: *mut SDL_GPUComputePass,
+    pub(super) _marker: PhantomData<&'a CommandBuffer>,
 }
-impl ComputePass {
+impl ComputePass<'_> {
     #[inline]
     pub fn raw(&self) -> *mut SDL_GPUComputePass {
         self.inner
@@ -1,10 +1,27 @@
 use libc::c_char;
 use std::ffi::{CStr, CString, NulError};
-use std::fmt::{Display, Error, Formatter};
+use std::fmt::{Debug, Display, Error, Formatter};
+use std::hash::{Hash, Hasher};
 use sys::guid::SDL_GUID;
 
 /// Wrapper around a `SDL_GUID`, a globally unique identifier
 /// for a joystick.
+///
+/// Unlike a `JoystickId`, which changes every time a device is connected, the
+/// GUID stays the same for the same kind of device, so its string form is
+/// suitable for persisting per-controller settings:
+///
+/// ```no_run
+/// use sdl3::joystick::Guid;
+///
+/// let sdl_context = sdl3::init().unwrap();
+/// let joystick_subsystem = sdl_context.joystick().unwrap();
+/// for id in joystick_subsystem.joysticks().unwrap() {
+///     let key = joystick_subsystem.guid_for_id(id).to_string();
+///     // ... save bindings under `key` ...
+///     assert_eq!(Guid::from_string(&key).unwrap().to_string(), key);
+/// }
+/// ```
 #[derive(Copy, Clone)]
 pub struct Guid {
     pub raw: sys::guid::SDL_GUID,
@@ -18,8 +35,22 @@ impl PartialEq for Guid {
 
 impl Eq for Guid {}
 
+impl Hash for Guid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.data.hash(state);
+    }
+}
+
+impl Debug for Guid {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "Guid({})", self.string())
+    }
+}
+
 impl Guid {
-    /// Create a GUID from a string representation.
+    /// Create a GUID from a string representation, as returned by `string()`.
+    ///
+    /// Invalid characters are treated as zeroes.
     #[doc(alias = "SDL_StringToGUID")]
     pub fn from_string(guid: &str) -> Result<Guid, NulError> {
         let guid = CString::new(guid)?;
@@ -81,3 +112,17 @@ impl Display for Guid {
         write!(f, "{}", self.string())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Guid;
+
+    #[test]
+    fn string_round_trip() {
+        let s = "030000005e0400008e02000014010000";
+        let guid = Guid::from_string(s).unwrap();
+        assert!(!guid.is_zero());
+        assert_eq!(guid.string(), s);
+        assert_eq!(Guid::from_string(&guid.to_string()).unwrap(), guid);
+    }
+}
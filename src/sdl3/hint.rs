@@ -77,6 +77,100 @@ pub fn get_video_minimize_on_focus_loss() -> bool {
     &*value == "1"
 }
 
+/// A hint that specifies a scale factor applied to relative mouse motion.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_SPEED_SCALE)
+///
+/// # Default
+/// The default is `1.0`. If system scaling is enabled (see
+/// [`set_mouse_relative_system_scale`]), this is applied after it.
+///
+/// # Example
+/// ```rust,no_run
+/// sdl3::hint::set_mouse_relative_speed_scale(0.5);
+/// ```
+pub fn set_mouse_relative_speed_scale(scale: f32) -> bool {
+    set(names::MOUSE_RELATIVE_SPEED_SCALE, &scale.to_string())
+}
+
+/// A hint that specifies a scale factor applied to relative mouse motion.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_SPEED_SCALE)
+///
+/// # Default
+/// Returns `1.0` if the hint is not set or cannot be parsed.
+pub fn get_mouse_relative_speed_scale() -> f32 {
+    get(names::MOUSE_RELATIVE_SPEED_SCALE)
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(1.0)
+}
+
+/// A hint that specifies whether relative mouse motion is scaled using the
+/// system mouse acceleration curve.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_SYSTEM_SCALE)
+///
+/// # Default
+/// This is disabled by default.
+///
+/// # Example
+/// ```rust,no_run
+/// sdl3::hint::set_mouse_relative_system_scale(true);
+/// ```
+pub fn set_mouse_relative_system_scale(value: bool) -> bool {
+    set(
+        names::MOUSE_RELATIVE_SYSTEM_SCALE,
+        if value { "1" } else { "0" },
+    )
+}
+
+/// A hint that specifies whether relative mouse motion is scaled using the
+/// system mouse acceleration curve.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_SYSTEM_SCALE)
+///
+/// # Default
+/// By default this will return `false`.
+pub fn get_mouse_relative_system_scale() -> bool {
+    let Some(value) = get(names::MOUSE_RELATIVE_SYSTEM_SCALE) else {
+        return false;
+    };
+    &*value == "1"
+}
+
+/// A hint that specifies whether warping the mouse in relative mode generates
+/// motion events.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_WARP_MOTION)
+///
+/// # Default
+/// This is disabled by default.
+///
+/// # Example
+/// ```rust,no_run
+/// sdl3::hint::set_mouse_relative_warp_motion(false);
+/// ```
+pub fn set_mouse_relative_warp_motion(value: bool) -> bool {
+    set(
+        names::MOUSE_RELATIVE_WARP_MOTION,
+        if value { "1" } else { "0" },
+    )
+}
+
+/// A hint that specifies whether warping the mouse in relative mode generates
+/// motion events.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_MOUSE_RELATIVE_WARP_MOTION)
+///
+/// # Default
+/// By default this will return `false`.
+pub fn get_mouse_relative_warp_motion() -> bool {
+    let Some(value) = get(names::MOUSE_RELATIVE_WARP_MOTION) else {
+        return false;
+    };
+    &*value == "1"
+}
+
 #[doc(alias = "SDL_SetHint")]
 pub fn set(name: &str, value: &str) -> bool {
     let name = CString::new(name).unwrap();
@@ -109,8 +109,12 @@ impl<'a> IOStream<'a> {
 
     /// Gets the stream's total size in bytes.
     ///
+    /// Reading, writing and seeking are provided by the [`io::Read`], [`io::Write`] and
+    /// [`io::Seek`] implementations.
+    ///
     /// Returns `None` if the stream size can't be determined
     /// (either because it doesn't make sense for the stream type, or there was an error).
+    #[doc(alias = "SDL_GetIOSize")]
     pub fn len(&self) -> Option<usize> {
         let result = unsafe { sys::iostream::SDL_GetIOSize(self.raw()) };
 
@@ -120,6 +124,20 @@ impl<'a> IOStream<'a> {
         }
     }
 
+    /// Gets the current read/write offset in the stream, in bytes from the start.
+    ///
+    /// Returns `None` if the stream is not seekable. Use the [`io::Seek`] implementation to
+    /// move the offset; it returns the resulting absolute position like `SDL_SeekIO`.
+    #[doc(alias = "SDL_TellIO")]
+    pub fn tell(&self) -> Option<u64> {
+        let result = unsafe { sys::iostream::SDL_TellIO(self.raw()) };
+
+        match result {
+            -1 => None,
+            v => Some(v as u64),
+        }
+    }
+
     /// Tells if the stream is empty.
     ///
     /// Returns `None` if the stream size can't be determined
@@ -181,7 +199,11 @@ impl io::Read for IOStream<'_> {
         let out_len = buf.len();
         let ret =
             unsafe { sys::iostream::SDL_ReadIO(self.raw(), buf.as_ptr() as *mut c_void, out_len) };
-        Ok(ret)
+        if ret == 0 && out_len != 0 && self.status() == IOStatus::Error {
+            Err(io::Error::other(get_error()))
+        } else {
+            Ok(ret)
+        }
     }
 }
 
@@ -209,6 +231,7 @@ impl io::Write for IOStream<'_> {
 }
 
 impl io::Seek for IOStream<'_> {
+    #[doc(alias = "SDL_SeekIO")]
     fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
         let (whence, offset) = match pos {
             io::SeekFrom::Start(pos) => (sys::iostream::SDL_IO_SEEK_SET, pos as i64),
@@ -4,7 +4,8 @@ use crate::sys;
 use crate::clear_error;
 use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
-use crate::guid::Guid;
+pub use crate::guid::Guid;
+use crate::result_from_bool;
 use crate::Error;
 use crate::JoystickSubsystem;
 use libc::{c_char, c_void};
@@ -36,6 +37,14 @@ impl JoystickSubsystem {
         }
     }
 
+    /// Return the implementation-dependent GUID of a joystick.
+    /// This can be called before any joysticks are opened.
+    #[doc(alias = "SDL_GetJoystickGUIDForID")]
+    pub fn guid_for_id(&self, joystick_id: JoystickId) -> Guid {
+        let raw = unsafe { sys::joystick::SDL_GetJoystickGUIDForID(joystick_id) };
+        Guid { raw }
+    }
+
     /// Attempt to open the joystick at index `joystick_index` and return it.
     #[doc(alias = "SDL_OpenJoystick")]
     pub fn open(&self, joystick_id: JoystickId) -> Result<Joystick, IntegerOrSdlError> {
@@ -52,6 +61,37 @@ impl JoystickSubsystem {
         }
     }
 
+    /// Set the rumble motors of an already opened joystick, identified by its
+    /// instance id, without needing the `Joystick` handle at hand.
+    ///
+    /// No transient open is performed: SDL stops the rumble as soon as a
+    /// joystick is closed, so an open-rumble-close sequence would have no
+    /// effect. If no `Joystick` (or `Gamepad`) for `joystick_id` is currently
+    /// open, an error is returned. See `Joystick::set_rumble` for the meaning
+    /// of the other parameters.
+    #[doc(alias = "SDL_RumbleJoystick")]
+    pub fn rumble(
+        &self,
+        joystick_id: JoystickId,
+        low_frequency_rumble: u16,
+        high_frequency_rumble: u16,
+        duration_ms: u32,
+    ) -> Result<(), Error> {
+        let joystick = unsafe { sys::joystick::SDL_GetJoystickFromID(joystick_id) };
+        if joystick.is_null() {
+            return Err(Error(format!("Joystick {} is not opened", joystick_id)));
+        }
+
+        result_from_bool(unsafe {
+            sys::joystick::SDL_RumbleJoystick(
+                joystick,
+                low_frequency_rumble,
+                high_frequency_rumble,
+                duration_ms,
+            )
+        })
+    }
+
     /// If state is `true` joystick events are processed, otherwise
     /// they're ignored.
     #[doc(alias = "SDL_SetJoystickEventsEnabled")]
@@ -225,7 +265,10 @@ impl Joystick {
         }
     }
 
-    /// Gets the position of the given `axis`.
+    /// Gets the position of the given `axis`, in the range `i16::MIN..=i16::MAX`.
+    ///
+    /// The value is updated when events are pumped, or by
+    /// [`JoystickSubsystem::update`] if joystick events are disabled.
     ///
     /// The function will fail if the joystick doesn't have the provided axis.
     #[doc(alias = "SDL_GetJoystickAxis")]
@@ -253,6 +296,27 @@ impl Joystick {
         }
     }
 
+    /// Gets the initial state of the given `axis`, as reported by the device
+    /// when it was opened.
+    ///
+    /// Returns `None` if the axis has no initial state, e.g. because it
+    /// doesn't exist or hasn't reported a value yet. This helps with axes that
+    /// rest at an extreme, such as throttles and triggers, which would
+    /// otherwise be indistinguishable from a centered axis at startup.
+    #[doc(alias = "SDL_GetJoystickAxisInitialState")]
+    pub fn axis_initial_state(&self, axis: u32) -> Option<i16> {
+        let axis = validate_int(axis, "axis").ok()?;
+        let mut state = 0;
+        let result =
+            unsafe { sys::joystick::SDL_GetJoystickAxisInitialState(self.raw, axis, &mut state) };
+
+        if result {
+            Some(state)
+        } else {
+            None
+        }
+    }
+
     /// Retrieve the number of buttons for this joystick
     #[doc(alias = "SDL_GetNumJoystickButtons")]
     pub fn num_buttons(&self) -> u32 {
@@ -294,7 +358,7 @@ impl Joystick {
         }
     }
 
-    /// Retrieve the number of balls for this joystick
+    /// Retrieve the number of hats for this joystick
     #[doc(alias = "SDL_GetNumJoystickHats")]
     pub fn num_hats(&self) -> u32 {
         let result = unsafe { sys::joystick::SDL_GetNumJoystickHats(self.raw) };
@@ -564,6 +564,9 @@ impl Keycode {
         }
     }
 
+    /// Gets a keycode from a name returned by [`Keycode::name`].
+    ///
+    /// Returns `None` if the name is unknown.
     #[doc(alias = "SDL_GetKeyFromName")]
     pub fn from_name(name: &str) -> Option<Keycode> {
         const UNKNOWN: u32 = sys::keycode::SDLK_UNKNOWN;
@@ -580,6 +583,9 @@ impl Keycode {
         }
     }
 
+    /// Gets a human-readable name for the key, e.g. `"A"` or `"Escape"`.
+    ///
+    /// Keys without a name return an empty string.
     #[doc(alias = "SDL_GetKeyName")]
     pub fn name(self) -> String {
         // The name string pointer's contents _might_ change, depending on the last call to SDL_GetKeyName.
@@ -1,9 +1,11 @@
+use crate::event::Event;
 use crate::rect::Rect;
 use crate::video::Window;
 use crate::EventPump;
 
 use crate::sys;
 use std::fmt;
+use std::ops::Range;
 use sys::video::SDL_GetWindowID;
 
 mod keycode;
@@ -17,6 +19,7 @@ bitflags! {
         const NOMOD = 0x0000;
         const LSHIFTMOD = 0x0001;
         const RSHIFTMOD = 0x0002;
+        const LEVEL5MOD = 0x0004;
         const LCTRLMOD = 0x0040;
         const RCTRLMOD = 0x0080;
         const LALTMOD = 0x0100;
@@ -26,7 +29,16 @@ bitflags! {
         const NUMMOD = 0x1000;
         const CAPSMOD = 0x2000;
         const MODEMOD = 0x4000;
+        const SCROLLMOD = 0x8000;
         const RESERVEDMOD = 0x8000;
+        /// Either shift key.
+        const SHIFTMOD = Self::LSHIFTMOD.bits() | Self::RSHIFTMOD.bits();
+        /// Either control key.
+        const CTRLMOD = Self::LCTRLMOD.bits() | Self::RCTRLMOD.bits();
+        /// Either alt key.
+        const ALTMOD = Self::LALTMOD.bits() | Self::RALTMOD.bits();
+        /// Either GUI (Windows/Command) key.
+        const GUIMOD = Self::LGUIMOD.bits() | Self::RGUIMOD.bits();
     }
 }
 
@@ -149,6 +161,33 @@ impl Iterator for PressedScancodeIterator<'_> {
     }
 }
 
+/// Returns an iterator over every scancode known to this crate, in numeric order.
+///
+/// `Scancode::Unknown`, `Scancode::Reserved` and `Scancode::Count` are skipped. Combined with
+/// [`Scancode::name`] and [`Scancode::from_name`] this can be used to list and persist key
+/// bindings.
+pub fn all_scancodes() -> impl Iterator<Item = Scancode> {
+    (1..sys::scancode::SDL_SCANCODE_COUNT.0)
+        .filter_map(Scancode::from_i32)
+        .filter(|&scancode| scancode != Scancode::Reserved)
+}
+
+/// Returns an iterator over every keycode known to this crate.
+///
+/// `Keycode::Unknown` and `Keycode::ScancodeMask` are skipped. Keycode names are layout
+/// dependent; prefer scancodes for bindings that should survive a layout change.
+pub fn all_keycodes() -> impl Iterator<Item = Keycode> {
+    let characters = 1..0x80;
+    let scancodes = (0..sys::scancode::SDL_SCANCODE_COUNT.0)
+        .map(|n| n | sys::keycode::SDLK_SCANCODE_MASK as i32);
+    let extended = (1..0x100).map(|n| n | sys::keycode::SDLK_EXTENDED_MASK as i32);
+    characters
+        .chain(scancodes)
+        .chain(extended)
+        .filter_map(Keycode::from_i32)
+        .filter(|&keycode| keycode != Keycode::ScancodeMask)
+}
+
 impl crate::Sdl {
     #[inline]
     pub fn keyboard(&self) -> KeyboardUtil {
@@ -191,11 +230,27 @@ impl KeyboardUtil {
         }
     }
 
+    /// Gets the current key modifier state for the keyboard.
+    ///
+    /// This can be queried at any time, e.g. while handling a mouse click.
+    /// Use `intersects` with the combined masks to check for either side of a
+    /// modifier:
+    ///
+    /// ```no_run
+    /// use sdl3::keyboard::Mod;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let multi_select = sdl_context.keyboard().mod_state().intersects(Mod::CTRLMOD);
+    /// ```
     #[doc(alias = "SDL_GetModState")]
     pub fn mod_state(&self) -> Mod {
-        unsafe { Mod::from_bits(sys::keyboard::SDL_GetModState() as u16).unwrap() }
+        unsafe { Mod::from_bits_truncate(sys::keyboard::SDL_GetModState()) }
     }
 
+    /// Overrides the current key modifier state.
+    ///
+    /// This does not change the keyboard state, only the modifier flags
+    /// reported by SDL.
     #[doc(alias = "SDL_SetModState")]
     pub fn set_mod_state(&self, flags: Mod) {
         unsafe {
@@ -251,6 +306,7 @@ impl TextInputUtil {
         }
     }
 
+    /// Checks whether the platform has on-screen keyboard support.
     #[doc(alias = "SDL_HasScreenKeyboardSupport")]
     pub fn has_screen_keyboard_support(&self) -> bool {
         unsafe { sys::keyboard::SDL_HasScreenKeyboardSupport() }
@@ -261,3 +317,147 @@ impl TextInputUtil {
         unsafe { sys::keyboard::SDL_ScreenKeyboardShown(window.raw()) }
     }
 }
+
+/// Keeps track of IME composition from text input events.
+///
+/// Feed every event to [`TextInputComposer::handle_event`]. While the user
+/// composes text (e.g. CJK input), [`composition`](Self::composition) holds the
+/// pre-edit text to display, typically underlined, with the IME cursor or
+/// selection given by [`selection`](Self::selection). Committed text is
+/// collected until it is taken with [`take_committed`](Self::take_committed).
+///
+/// ```no_run
+/// use sdl3::keyboard::TextInputComposer;
+///
+/// let sdl_context = sdl3::init().unwrap();
+/// let mut event_pump = sdl_context.event_pump().unwrap();
+/// let mut composer = TextInputComposer::new();
+/// let mut line = String::new();
+///
+/// for event in event_pump.poll_iter() {
+///     composer.handle_event(&event);
+/// }
+/// line.push_str(&composer.take_committed());
+/// // draw `line`, followed by `composer.composition()` underlined
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextInputComposer {
+    composition: String,
+    start: i32,
+    length: i32,
+    committed: String,
+}
+
+impl TextInputComposer {
+    /// Creates a composer with no composition or committed text.
+    pub fn new() -> TextInputComposer {
+        Self::default()
+    }
+
+    /// Updates the state from a `TextEditing` or `TextInput` event.
+    ///
+    /// Returns `false` and does nothing for any other event.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::TextEditing {
+                text,
+                start,
+                length,
+                ..
+            } => {
+                self.composition.clone_from(text);
+                self.start = *start;
+                self.length = *length;
+                true
+            }
+            Event::TextInput { text, .. } => {
+                self.committed.push_str(text);
+                self.composition.clear();
+                self.start = 0;
+                self.length = 0;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The text currently being composed, not yet committed.
+    pub fn composition(&self) -> &str {
+        &self.composition
+    }
+
+    /// Returns `true` while there is composition text.
+    pub fn is_composing(&self) -> bool {
+        !self.composition.is_empty()
+    }
+
+    /// The cursor position or selection within the composition text, as a
+    /// range of characters. Empty if it is only a cursor, `None` if the IME
+    /// didn't report one.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let start = usize::try_from(self.start).ok()?;
+        let length = usize::try_from(self.length).unwrap_or(0);
+        Some(start..start + length)
+    }
+
+    /// Same as [`selection`](Self::selection), but as a range of bytes that can
+    /// be used to slice [`composition`](Self::composition).
+    pub fn selection_bytes(&self) -> Option<Range<usize>> {
+        let chars = self.selection()?;
+        let byte_offset = |n: usize| {
+            self.composition
+                .char_indices()
+                .nth(n)
+                .map_or(self.composition.len(), |(i, _)| i)
+        };
+        Some(byte_offset(chars.start)..byte_offset(chars.end))
+    }
+
+    /// Text committed since the last call to [`take_committed`](Self::take_committed).
+    pub fn committed(&self) -> &str {
+        &self.committed
+    }
+
+    /// Returns and clears the committed text.
+    pub fn take_committed(&mut self) -> String {
+        std::mem::take(&mut self.committed)
+    }
+
+    /// Discards both the composition and the committed text.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TextInputComposer;
+    use crate::event::Event;
+
+    #[test]
+    fn test_text_input_composer() {
+        let mut composer = TextInputComposer::new();
+        assert!(!composer.handle_event(&Event::Quit { timestamp: 0 }));
+
+        assert!(composer.handle_event(&Event::TextEditing {
+            timestamp: 0,
+            window_id: 1,
+            text: "にほ".to_owned(),
+            start: 1,
+            length: 1,
+        }));
+        assert!(composer.is_composing());
+        assert_eq!(composer.composition(), "にほ");
+        assert_eq!(composer.selection(), Some(1..2));
+        assert_eq!(composer.selection_bytes(), Some(3..6));
+
+        composer.handle_event(&Event::TextInput {
+            timestamp: 0,
+            window_id: 1,
+            text: "日本".to_owned(),
+        });
+        assert!(!composer.is_composing());
+        assert_eq!(composer.take_committed(), "日本");
+        assert_eq!(composer.committed(), "");
+    }
+}
@@ -553,6 +553,9 @@ impl Scancode {
         }
     }
 
+    /// Gets a scancode from a name returned by [`Scancode::name`].
+    ///
+    /// Returns `None` if the name is unknown.
     #[doc(alias = "SDL_GetScancodeFromName")]
     pub fn from_name(name: &str) -> Option<Scancode> {
         unsafe {
@@ -567,6 +570,10 @@ impl Scancode {
         }
     }
 
+    /// Gets a human-readable name for the scancode, e.g. `"Left Shift"`.
+    ///
+    /// The names are not translated and do not depend on the keyboard layout, so they round-trip
+    /// through [`Scancode::from_name`]. Scancodes without a name return an empty string.
     #[doc(alias = "SDL_GetScancodeName")]
     pub fn name(self) -> &'static str {
         // The name string pointer lives in static, read-only memory.
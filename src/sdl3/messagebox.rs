@@ -3,6 +3,8 @@ use std::ffi::{CString, NulError};
 use std::fmt;
 use std::os::raw::{c_char, c_int};
 use std::ptr;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
 
 use crate::get_error;
 use crate::video::Window;
@@ -70,6 +72,17 @@ pub enum ClickedButton<'a> {
     CustomButton(&'a ButtonData<'a>),
 }
 
+/// How `show_simple_message_box_threaded` showed the message box.
+#[derive(Debug)]
+pub enum ThreadedMessageBox {
+    /// The message box is shown from another thread; its result is sent
+    /// through the channel once the user dismisses it.
+    Threaded(Receiver<Result<(), ShowMessageError>>),
+    /// The platform can't show message boxes off the main thread, so the call
+    /// blocked until the message box was dismissed.
+    RanSynchronously(Result<(), ShowMessageError>),
+}
+
 impl From<MessageBoxColorScheme> for [sys::messagebox::SDL_MessageBoxColor; 5] {
     fn from(scheme: MessageBoxColorScheme) -> [sys::messagebox::SDL_MessageBoxColor; 5] {
         fn to_message_box_color(t: (u8, u8, u8)) -> sys::messagebox::SDL_MessageBoxColor {
@@ -182,6 +195,55 @@ where
     }
 }
 
+/// Show a simple message box without blocking the calling thread.
+///
+/// The message box is shown from a dedicated thread and the result is sent
+/// through the returned `ThreadedMessageBox::Threaded` channel once the user
+/// dismisses it, so the event loop can keep running in the meantime.
+///
+/// SDL requires a message box with a parent window to be shown from the
+/// thread that created the window, so no parent can be given here.
+///
+/// On macOS and iOS message boxes can only be shown from the main thread. On
+/// those platforms this function blocks like `show_simple_message_box` and
+/// returns `ThreadedMessageBox::RanSynchronously` with the result.
+///
+/// ```no_run
+/// use sdl3::messagebox::{show_simple_message_box_threaded, MessageBoxFlag, ThreadedMessageBox};
+///
+/// match show_simple_message_box_threaded(MessageBoxFlag::INFORMATION, "Saved", "Game saved.") {
+///     ThreadedMessageBox::Threaded(done) => {
+///         // ... keep pumping events ...
+///         if let Ok(result) = done.try_recv() {
+///             result.unwrap();
+///         }
+///     }
+///     ThreadedMessageBox::RanSynchronously(result) => result.unwrap(),
+/// }
+/// ```
+#[doc(alias = "SDL_ShowSimpleMessageBox")]
+pub fn show_simple_message_box_threaded(
+    flags: MessageBoxFlag,
+    title: &str,
+    message: &str,
+) -> ThreadedMessageBox {
+    if cfg!(any(target_os = "macos", target_os = "ios")) {
+        return ThreadedMessageBox::RanSynchronously(show_simple_message_box(
+            flags, title, message, None,
+        ));
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let title = title.to_owned();
+    let message = message.to_owned();
+    thread::spawn(move || {
+        // The caller may have dropped the receiver if it isn't interested in the result.
+        let _ = sender.send(show_simple_message_box(flags, &title, &message, None));
+    });
+
+    ThreadedMessageBox::Threaded(receiver)
+}
+
 /// Show a customizable message box.
 ///
 /// An array of buttons is required for it to work. The array can be empty,
@@ -1,4 +1,5 @@
 use crate::get_error;
+use crate::result_from_bool;
 use crate::surface::SurfaceRef;
 use crate::sys;
 use crate::video;
@@ -410,6 +411,28 @@ impl MouseUtil {
         }
     }
 
+    /// Gets the position of the cursor in desktop coordinates, regardless of
+    /// which window (if any) has focus.
+    #[doc(alias = "SDL_GetGlobalMouseState")]
+    pub fn global_position(&self) -> (f32, f32) {
+        let state = self.global_state();
+        (state.x(), state.y())
+    }
+
+    /// Gets the button state and the position of the cursor in desktop
+    /// coordinates, regardless of which window (if any) has focus.
+    ///
+    /// Unlike `EventPump::mouse_state`, this queries the OS directly and also
+    /// works while the window is in the background.
+    #[doc(alias = "SDL_GetGlobalMouseState")]
+    pub fn global_state(&self) -> MouseState {
+        let mut x = 0.;
+        let mut y = 0.;
+        let mouse_state: u32 = unsafe { sys::mouse::SDL_GetGlobalMouseState(&mut x, &mut y) };
+
+        MouseState { mouse_state, x, y }
+    }
+
     #[doc(alias = "SDL_WarpMouseInWindow")]
     pub fn warp_mouse_in_window(&self, window: &video::Window, x: f32, y: f32) {
         unsafe {
@@ -417,10 +440,44 @@ impl MouseUtil {
         }
     }
 
+    /// Enables or disables relative mouse mode for `window`.
+    ///
+    /// While enabled the cursor is hidden and confined, and motion events keep reporting
+    /// relative motion even when the cursor cannot move further. See
+    /// [`Window::set_relative_mouse_warp`](video::Window::set_relative_mouse_warp) to restore
+    /// the cursor position when relative mode is turned off.
     #[doc(alias = "SDL_SetWindowRelativeMouseMode")]
     pub fn set_relative_mouse_mode(&self, window: &video::Window, on: bool) {
+        let context = window.context();
+        let mut restore = context.relative_mouse_restore.lock().unwrap();
         unsafe {
+            let was_on = SDL_GetWindowRelativeMouseMode(window.raw());
+            if restore.enabled && on && !was_on {
+                let mut x = 0.;
+                let mut y = 0.;
+                // `SDL_GetMouseState` is relative to the focused window, so convert the global
+                // position when `window` doesn't have mouse focus.
+                if sys::mouse::SDL_GetMouseFocus() == window.raw() {
+                    sys::mouse::SDL_GetMouseState(&mut x, &mut y);
+                    restore.position = Some((x, y));
+                } else {
+                    let mut window_x = 0;
+                    let mut window_y = 0;
+                    sys::mouse::SDL_GetGlobalMouseState(&mut x, &mut y);
+                    restore.position = sys::video::SDL_GetWindowPosition(
+                        window.raw(),
+                        &mut window_x,
+                        &mut window_y,
+                    )
+                    .then_some((x - window_x as f32, y - window_y as f32));
+                }
+            }
             SDL_SetWindowRelativeMouseMode(window.raw(), on);
+            if !on {
+                if let Some((x, y)) = restore.position.take() {
+                    sys::mouse::SDL_WarpMouseInWindow(window.raw(), x, y);
+                }
+            }
         }
     }
 
@@ -429,20 +486,36 @@ impl MouseUtil {
         unsafe { SDL_GetWindowRelativeMouseMode(window.raw()) }
     }
 
+    /// Returns whether the cursor is currently shown.
     #[doc(alias = "SDL_CursorVisible")]
     pub fn is_cursor_showing(&self) -> bool {
         unsafe { sys::mouse::SDL_CursorVisible() }
     }
 
+    /// Shows or hides the cursor.
+    ///
+    /// This is independent of relative mouse mode: a hidden cursor still
+    /// moves and generates motion events. Showing it again restores the
+    /// cursor that was last set with `Cursor::set`. Use
+    /// [`MouseUtil::try_show_cursor`] to find out whether it failed.
     #[doc(alias = "SDL_ShowCursor")]
+    #[doc(alias = "SDL_HideCursor")]
     pub fn show_cursor(&self, show: bool) {
-        unsafe {
+        let _ = self.try_show_cursor(show);
+    }
+
+    /// Shows or hides the cursor like [`MouseUtil::show_cursor`], returning
+    /// SDL's error on failure.
+    #[doc(alias = "SDL_ShowCursor")]
+    #[doc(alias = "SDL_HideCursor")]
+    pub fn try_show_cursor(&self, show: bool) -> Result<(), Error> {
+        result_from_bool(unsafe {
             if show {
-                sys::mouse::SDL_ShowCursor();
+                sys::mouse::SDL_ShowCursor()
             } else {
-                sys::mouse::SDL_HideCursor();
+                sys::mouse::SDL_HideCursor()
             }
-        }
+        })
     }
 
     #[doc(alias = "SDL_CaptureMouse")]
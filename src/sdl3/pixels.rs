@@ -214,6 +214,60 @@ impl From<(u8, u8, u8, u8)> for Color {
     }
 }
 
+/// A color with floating-point channels, usually in the range `0.0..=1.0`.
+///
+/// Has the same memory layout as `SDL_FColor`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl FColor {
+    #[inline]
+    #[allow(non_snake_case)]
+    pub const fn RGB(r: f32, g: f32, b: f32) -> FColor {
+        FColor { r, g, b, a: 1.0 }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub const fn RGBA(r: f32, g: f32, b: f32, a: f32) -> FColor {
+        FColor { r, g, b, a }
+    }
+}
+
+impl From<Color> for FColor {
+    fn from(color: Color) -> FColor {
+        FColor::RGBA(
+            color.r as f32 / 255.0,
+            color.g as f32 / 255.0,
+            color.b as f32 / 255.0,
+            color.a as f32 / 255.0,
+        )
+    }
+}
+
+impl From<FColor> for sys::pixels::SDL_FColor {
+    fn from(val: FColor) -> Self {
+        sys::pixels::SDL_FColor {
+            r: val.r,
+            g: val.g,
+            b: val.b,
+            a: val.a,
+        }
+    }
+}
+
+impl From<sys::pixels::SDL_FColor> for FColor {
+    fn from(raw: sys::pixels::SDL_FColor) -> FColor {
+        FColor::RGBA(raw.r, raw.g, raw.b, raw.a)
+    }
+}
+
 pub struct PixelMasks {
     /// Bits per pixel; usually 15, 16, or 32
     pub bpp: u8,
@@ -442,3 +496,48 @@ impl TryFrom<SDL_PixelFormat> for PixelFormat {
         }
     }
 }
+
+/// A colorspace, describing how pixel values map to colors.
+#[repr(u32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Colorspace {
+    Unknown = sys::pixels::SDL_COLORSPACE_UNKNOWN.0,
+    /// sRGB, the default for most content.
+    Srgb = sys::pixels::SDL_COLORSPACE_SRGB.0,
+    /// Linear sRGB; with float formats values above 1.0 are used for HDR output.
+    SrgbLinear = sys::pixels::SDL_COLORSPACE_SRGB_LINEAR.0,
+    /// BT.2020 primaries with the PQ transfer function.
+    Hdr10 = sys::pixels::SDL_COLORSPACE_HDR10.0,
+    Jpeg = sys::pixels::SDL_COLORSPACE_JPEG.0,
+    Bt601Limited = sys::pixels::SDL_COLORSPACE_BT601_LIMITED.0,
+    Bt601Full = sys::pixels::SDL_COLORSPACE_BT601_FULL.0,
+    Bt709Limited = sys::pixels::SDL_COLORSPACE_BT709_LIMITED.0,
+    Bt709Full = sys::pixels::SDL_COLORSPACE_BT709_FULL.0,
+    Bt2020Limited = sys::pixels::SDL_COLORSPACE_BT2020_LIMITED.0,
+    Bt2020Full = sys::pixels::SDL_COLORSPACE_BT2020_FULL.0,
+}
+
+impl Colorspace {
+    /// Converts a raw colorspace, returning `Colorspace::Unknown` for values not listed here.
+    pub fn from_ll(raw: sys::pixels::SDL_Colorspace) -> Colorspace {
+        match raw {
+            sys::pixels::SDL_COLORSPACE_SRGB => Colorspace::Srgb,
+            sys::pixels::SDL_COLORSPACE_SRGB_LINEAR => Colorspace::SrgbLinear,
+            sys::pixels::SDL_COLORSPACE_HDR10 => Colorspace::Hdr10,
+            sys::pixels::SDL_COLORSPACE_JPEG => Colorspace::Jpeg,
+            sys::pixels::SDL_COLORSPACE_BT601_LIMITED => Colorspace::Bt601Limited,
+            sys::pixels::SDL_COLORSPACE_BT601_FULL => Colorspace::Bt601Full,
+            sys::pixels::SDL_COLORSPACE_BT709_LIMITED => Colorspace::Bt709Limited,
+            sys::pixels::SDL_COLORSPACE_BT709_FULL => Colorspace::Bt709Full,
+            sys::pixels::SDL_COLORSPACE_BT2020_LIMITED => Colorspace::Bt2020Limited,
+            sys::pixels::SDL_COLORSPACE_BT2020_FULL => Colorspace::Bt2020Full,
+            _ => Colorspace::Unknown,
+        }
+    }
+}
+
+impl From<Colorspace> for sys::pixels::SDL_Colorspace {
+    fn from(colorspace: Colorspace) -> sys::pixels::SDL_Colorspace {
+        sys::pixels::SDL_Colorspace(colorspace as u32)
+    }
+}
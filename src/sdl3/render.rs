@@ -31,13 +31,16 @@
 use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
 use crate::pixels;
+use crate::pixels::FColor;
+use crate::properties::Properties;
 use crate::rect::Point;
 use crate::rect::Rect;
+use crate::result_from_bool;
 use crate::surface::{Surface, SurfaceContext, SurfaceRef};
 use crate::sys;
 use crate::video::{Window, WindowContext};
 use crate::Error;
-use libc::{c_double, c_int};
+use libc::{c_char, c_double, c_int};
 use pixels::PixelFormat;
 use std::convert::{Into, TryFrom, TryInto};
 use std::error;
@@ -46,7 +49,7 @@ use std::fmt;
 #[cfg(not(feature = "unsafe_textures"))]
 use std::marker::PhantomData;
 use std::mem;
-use std::mem::{transmute, MaybeUninit};
+use std::mem::MaybeUninit;
 use std::ops::Deref;
 use std::ptr;
 use std::rc::Rc;
@@ -491,6 +494,7 @@ pub struct Canvas<T: RenderTarget> {
     target: T,
     context: Rc<RendererContext<T::Context>>,
     default_pixel_format: PixelFormat,
+    /// The name of the render driver in use, as reported by `SDL_GetRendererName`.
     pub renderer_name: String,
 }
 
@@ -651,6 +655,9 @@ impl<T: RenderTarget> Canvas<T> {
     /// You do not need to call `present` after drawing in the Canvas in the closure, the changes
     /// are applied directly to the `Texture` instead of a hidden buffer.
     ///
+    /// Afterwards the render target that was current before the call is restored, so this can be
+    /// nested and combined with `set_render_target`.
+    ///
     /// # Errors
     ///
     /// * returns `TargetRenderError::NotSupported` if the renderer does not support the use of
@@ -787,16 +794,59 @@ impl<T: RenderTarget> Canvas<T> {
         for<'r> F: FnMut(&'r mut Canvas<T>, &U),
         I: Iterator<Item = &'s (&'a mut Texture, U)>,
     {
+        let target = unsafe { self.get_raw_target() };
         for &(ref texture, ref user_context) in textures {
             unsafe { self.set_raw_target(texture.raw) }
                 .map_err(|e| TargetRenderError::SdlError(e))?;
             f(self, &user_context);
         }
         // reset the target to its source
-        unsafe { self.set_raw_target(ptr::null_mut()) }
-            .map_err(|e| TargetRenderError::SdlError(e))?;
+        unsafe { self.set_raw_target(target) }.map_err(|e| TargetRenderError::SdlError(e))?;
         Ok(())
     }
+
+    /// Sets the render target to `texture`, or back to the `Canvas` source (the `Window` or
+    /// `Surface`) with `None`.
+    ///
+    /// Unlike `with_texture_canvas`, the target stays set until it is changed again, which is
+    /// convenient when switching between several targets within a frame (e.g. ping-pong
+    /// rendering). Destroying a texture while it is the render target resets the target to the
+    /// source. Scale, viewport and clip rectangle are tracked per target.
+    ///
+    /// Returns an error if `texture` was not created with `TextureAccess::Target`.
+    #[doc(alias = "SDL_SetRenderTarget")]
+    pub fn set_render_target(&mut self, texture: Option<&mut Texture>) -> Result<(), Error> {
+        let raw = match texture {
+            Some(texture) => {
+                if texture.query().access != TextureAccess::Target {
+                    return Err(Error(
+                        "Texture was not created with TextureAccess::Target".to_owned(),
+                    ));
+                }
+                texture.raw
+            }
+            None => ptr::null_mut(),
+        };
+        unsafe { self.set_raw_target(raw) }
+    }
+
+    /// Returns `true` if a texture, rather than the `Canvas` source, is the current render
+    /// target.
+    ///
+    /// There is no getter returning the target texture itself: the `Canvas` doesn't own its
+    /// textures, so a reference to the target could outlive it. Use
+    /// [`Canvas::is_render_target`] to check which texture is the target.
+    #[doc(alias = "SDL_GetRenderTarget")]
+    pub fn has_render_target(&self) -> bool {
+        !unsafe { self.get_raw_target() }.is_null()
+    }
+
+    /// Returns `true` if `texture` is the current render target.
+    #[doc(alias = "SDL_GetRenderTarget")]
+    pub fn is_render_target(&self, texture: &Texture) -> bool {
+        let target = unsafe { self.get_raw_target() };
+        target == texture.raw
+    }
 }
 
 /// Creates Textures that cannot outlive the creator
@@ -841,6 +891,51 @@ pub fn create_renderer(
     }
 }
 
+/// Creates a `WindowCanvas` whose output uses `colorspace`.
+///
+/// The output colorspace can only be chosen at creation. `Colorspace::Srgb` is the default;
+/// `Colorspace::SrgbLinear` enables HDR output on the renderers that support it (currently
+/// direct3d11, direct3d12, metal and vulkan). Drawing still uses sRGB, but color values may go
+/// above 1.0 and float textures can carry HDR content. Use [`Canvas::hdr_enabled`] to check
+/// whether HDR is actually active.
+#[doc(alias = "SDL_CreateRendererWithProperties")]
+pub fn create_renderer_with_output_colorspace(
+    window: Window,
+    renderer_name: Option<&CStr>,
+    colorspace: pixels::Colorspace,
+) -> Result<WindowCanvas, IntegerOrSdlError> {
+    use crate::common::IntegerOrSdlError::*;
+    let raw = unsafe {
+        let props = sys::properties::SDL_CreateProperties();
+        sys::properties::SDL_SetPointerProperty(
+            props,
+            sys::render::SDL_PROP_RENDERER_CREATE_WINDOW_POINTER,
+            window.raw() as *mut _,
+        );
+        if let Some(renderer_name) = renderer_name {
+            sys::properties::SDL_SetStringProperty(
+                props,
+                sys::render::SDL_PROP_RENDERER_CREATE_NAME_STRING,
+                renderer_name.as_ptr(),
+            );
+        }
+        sys::properties::SDL_SetNumberProperty(
+            props,
+            sys::render::SDL_PROP_RENDERER_CREATE_OUTPUT_COLORSPACE_NUMBER,
+            sys::pixels::SDL_Colorspace::from(colorspace).0 as i64,
+        );
+        let raw = sys::render::SDL_CreateRendererWithProperties(props);
+        sys::properties::SDL_DestroyProperties(props);
+        raw
+    };
+
+    if raw.is_null() {
+        Err(SdlError(get_error()))
+    } else {
+        Ok(Canvas::from_window_and_renderer(window, raw))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum TextureValueError {
     WidthOverflows(u32),
@@ -949,6 +1044,44 @@ impl TryFrom<sdl3_sys::everything::SDL_ScaleMode> for ScaleMode {
     }
 }
 
+/// Mirroring applied when rendering a texture with `Canvas::copy_ex_f`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum FlipMode {
+    /// Do not flip
+    None,
+    /// Flip left-right
+    Horizontal,
+    /// Flip top-bottom
+    Vertical,
+    /// Flip both left-right and top-bottom
+    Both,
+}
+
+impl FlipMode {
+    /// Builds a `FlipMode` from separate horizontal and vertical flags.
+    pub fn from_flags(horizontal: bool, vertical: bool) -> FlipMode {
+        match (horizontal, vertical) {
+            (false, false) => FlipMode::None,
+            (true, false) => FlipMode::Horizontal,
+            (false, true) => FlipMode::Vertical,
+            (true, true) => FlipMode::Both,
+        }
+    }
+}
+
+impl From<FlipMode> for sys::surface::SDL_FlipMode {
+    fn from(flip: FlipMode) -> sys::surface::SDL_FlipMode {
+        match flip {
+            FlipMode::None => SDL_FLIP_NONE,
+            FlipMode::Horizontal => SDL_FLIP_HORIZONTAL,
+            FlipMode::Vertical => SDL_FLIP_VERTICAL,
+            FlipMode::Both => {
+                sys::surface::SDL_FlipMode(SDL_FLIP_HORIZONTAL.0 | SDL_FLIP_VERTICAL.0)
+            }
+        }
+    }
+}
+
 /// Texture-creating methods for the renderer
 impl<T> TextureCreator<T> {
     // this can prevent introducing UB until
@@ -1132,6 +1265,9 @@ impl<T: RenderTarget> Canvas<T> {
     }
 
     /// Sets the blend mode used for drawing operations (Fill and Line).
+    ///
+    /// Panics if SDL rejects the blend mode; see
+    /// [`Canvas::set_draw_blend_mode`] for a fallible version.
     #[doc(alias = "SDL_SetRenderDrawBlendMode")]
     pub fn set_blend_mode(&mut self, blend: BlendMode) {
         let ret =
@@ -1158,15 +1294,54 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Sets the blend mode used for drawing primitives, i.e. the points, lines
+    /// and rects of the `draw_*` and `fill_*` methods.
+    ///
+    /// Texture copies are not affected, they use the blend mode of the
+    /// texture itself (see `Texture::set_blend_mode`), so e.g. additive lines
+    /// can be drawn over an alpha-blended scene.
+    ///
+    /// Fails if the renderer doesn't support `mode`.
+    #[doc(alias = "SDL_SetRenderDrawBlendMode")]
+    pub fn set_draw_blend_mode(&mut self, mode: BlendMode) -> Result<(), Error> {
+        result_from_bool(unsafe {
+            sys::render::SDL_SetRenderDrawBlendMode(self.context.raw, mode as u32)
+        })
+    }
+
+    /// Gets the blend mode used for drawing primitives.
+    ///
+    /// Only the predefined modes of [`BlendMode`] can be represented. If a
+    /// custom mode composed with `SDL_ComposeCustomBlendMode` was set through
+    /// the raw renderer, an error is returned instead.
+    #[doc(alias = "SDL_GetRenderDrawBlendMode")]
+    pub fn draw_blend_mode(&self) -> Result<BlendMode, Error> {
+        let mut blend = sys::blendmode::SDL_BLENDMODE_NONE;
+        result_from_bool(unsafe {
+            sys::render::SDL_GetRenderDrawBlendMode(self.context.raw, &mut blend)
+        })?;
+        BlendMode::try_from(blend)
+            .map_err(|_| Error(format!("Unsupported draw blend mode: {:#x}", blend)))
+    }
+
     /// Clears the current rendering target with the drawing color.
+    ///
+    /// Panics if SDL fails to clear, which only happens with an invalid
+    /// renderer. Use [`Canvas::try_clear`] to get the error instead.
     #[doc(alias = "SDL_RenderClear")]
     pub fn clear(&mut self) {
-        let ret = unsafe { sys::render::SDL_RenderClear(self.context.raw) };
-        if !ret {
-            panic!("Could not clear: {}", get_error())
+        if let Err(e) = self.try_clear() {
+            panic!("Could not clear: {}", e)
         }
     }
 
+    /// Clears the current rendering target with the drawing color, returning
+    /// SDL's error on failure.
+    #[doc(alias = "SDL_RenderClear")]
+    pub fn try_clear(&mut self) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::render::SDL_RenderClear(self.context.raw) })
+    }
+
     /// Updates the screen with any rendering performed since the previous call.
     ///
     /// SDL's rendering functions operate on a backbuffer; that is, calling a
@@ -1181,6 +1356,13 @@ impl<T: RenderTarget> Canvas<T> {
         unsafe { sys::render::SDL_RenderPresent(self.context.raw) }
     }
 
+    /// Updates the screen like [`Canvas::present`], returning SDL's error on
+    /// failure.
+    #[doc(alias = "SDL_RenderPresent")]
+    pub fn try_present(&mut self) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::render::SDL_RenderPresent(self.context.raw) })
+    }
+
     /// Gets the output size of a rendering context.
     #[doc(alias = "SDL_GetCurrentRenderOutputSize")]
     pub fn output_size(&self) -> Result<(u32, u32), Error> {
@@ -1198,6 +1380,150 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Gets the properties associated with the renderer.
+    #[doc(alias = "SDL_GetRendererProperties")]
+    pub fn get_properties(&self) -> Result<Properties, Error> {
+        let props = unsafe { sys::render::SDL_GetRendererProperties(self.context.raw) };
+        if props == 0 {
+            Err(get_error())
+        } else {
+            Ok(Properties::const_from_ll(props))
+        }
+    }
+
+    /// Reads the renderer property `name` with one of the `SDL_Get*Property` functions.
+    fn renderer_property<V>(
+        &self,
+        get: unsafe extern "C" fn(SDL_PropertiesID, *const c_char, V) -> V,
+        name: *const c_char,
+        default: V,
+    ) -> Result<V, Error> {
+        let props = unsafe { sys::render::SDL_GetRendererProperties(self.context.raw) };
+        if props == 0 {
+            Err(get_error())
+        } else {
+            Ok(unsafe { get(props, name, default) })
+        }
+    }
+
+    /// Gets the colorspace used for output to the display.
+    #[doc(alias = "SDL_PROP_RENDERER_OUTPUT_COLORSPACE_NUMBER")]
+    pub fn output_colorspace(&self) -> pixels::Colorspace {
+        let raw = self
+            .renderer_property(
+                sys::properties::SDL_GetNumberProperty,
+                sys::render::SDL_PROP_RENDERER_OUTPUT_COLORSPACE_NUMBER,
+                0,
+            )
+            .unwrap_or(0);
+        pixels::Colorspace::from_ll(sys::pixels::SDL_Colorspace(raw as u32))
+    }
+
+    /// Returns `true` if the output is HDR capable and HDR is enabled on the display.
+    ///
+    /// This requires the canvas to be created with a linear output colorspace, see
+    /// [`create_renderer_with_output_colorspace`]. The value can change while the program runs,
+    /// e.g. when the window moves to another display.
+    #[doc(alias = "SDL_PROP_RENDERER_HDR_ENABLED_BOOLEAN")]
+    pub fn hdr_enabled(&self) -> bool {
+        self.renderer_property(
+            sys::properties::SDL_GetBooleanProperty,
+            sys::render::SDL_PROP_RENDERER_HDR_ENABLED_BOOLEAN,
+            false,
+        )
+        .unwrap_or(false)
+    }
+
+    /// Gets the value of SDR white in the linear output colorspace.
+    ///
+    /// This is 1.0 unless HDR is enabled, in which case SDR content should be scaled by it.
+    #[doc(alias = "SDL_PROP_RENDERER_SDR_WHITE_POINT_FLOAT")]
+    pub fn sdr_white_point(&self) -> f32 {
+        self.renderer_property(
+            sys::properties::SDL_GetFloatProperty,
+            sys::render::SDL_PROP_RENDERER_SDR_WHITE_POINT_FLOAT,
+            1.0,
+        )
+        .unwrap_or(1.0)
+    }
+
+    /// Gets the additional dynamic range available above SDR white, as a multiple of
+    /// [`Canvas::sdr_white_point`].
+    ///
+    /// This is 1.0 when HDR is not enabled.
+    #[doc(alias = "SDL_PROP_RENDERER_HDR_HEADROOM_FLOAT")]
+    pub fn hdr_headroom(&self) -> f32 {
+        self.renderer_property(
+            sys::properties::SDL_GetFloatProperty,
+            sys::render::SDL_PROP_RENDERER_HDR_HEADROOM_FLOAT,
+            1.0,
+        )
+        .unwrap_or(1.0)
+    }
+
+    /// Gets the `CAMetalLayer` of a Metal renderer, for interop with native Metal code.
+    ///
+    /// Only available on macOS, iOS and tvOS when the canvas uses the `metal` driver;
+    /// returns `None` otherwise. The pointer can be cast to a `CAMetalLayer *`. It is
+    /// owned by SDL and only valid while the canvas is alive.
+    #[doc(alias = "SDL_GetRenderMetalLayer")]
+    pub fn metal_layer(&self) -> Option<*mut libc::c_void> {
+        let layer = unsafe { sys::render::SDL_GetRenderMetalLayer(self.context.raw) };
+        if layer.is_null() {
+            None
+        } else {
+            Some(layer)
+        }
+    }
+
+    /// Gets the `ID3D11Device` of a Direct3D 11 renderer, for interop with native
+    /// Direct3D code.
+    ///
+    /// Only available on Windows when the canvas uses the `direct3d11` driver;
+    /// returns `None` otherwise. The device is owned by SDL and only valid while the
+    /// canvas is alive; add a COM reference if you need to keep it longer.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D11_DEVICE_POINTER")]
+    pub fn d3d11_device(&self) -> Option<*mut libc::c_void> {
+        self.renderer_property(
+            sys::properties::SDL_GetPointerProperty,
+            sys::render::SDL_PROP_RENDERER_D3D11_DEVICE_POINTER,
+            ptr::null_mut(),
+        )
+        .ok()
+        .filter(|device| !device.is_null())
+    }
+
+    /// Gets the `ID3D12Device` of a Direct3D 12 renderer, for interop with native
+    /// Direct3D code.
+    ///
+    /// Only available on Windows and Xbox when the canvas uses the `direct3d12`
+    /// driver; returns `None` otherwise. The device is owned by SDL and only valid
+    /// while the canvas is alive.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D12_DEVICE_POINTER")]
+    pub fn d3d12_device(&self) -> Option<*mut libc::c_void> {
+        self.renderer_property(
+            sys::properties::SDL_GetPointerProperty,
+            sys::render::SDL_PROP_RENDERER_D3D12_DEVICE_POINTER,
+            ptr::null_mut(),
+        )
+        .ok()
+        .filter(|device| !device.is_null())
+    }
+
+    /// Gets the maximum width and height of textures the renderer can create.
+    ///
+    /// Returns `(0, 0)` if the renderer doesn't report a limit.
+    #[doc(alias = "SDL_PROP_RENDERER_MAX_TEXTURE_SIZE_NUMBER")]
+    pub fn max_texture_size(&self) -> Result<(u32, u32), Error> {
+        let size = self.renderer_property(
+            sys::properties::SDL_GetNumberProperty,
+            sys::render::SDL_PROP_RENDERER_MAX_TEXTURE_SIZE_NUMBER,
+            0,
+        )?;
+        let size = size.clamp(0, u32::MAX as i64) as u32;
+        Ok((size, size))
+    }
+
     /// Sets a device independent resolution for rendering.
     #[doc(alias = "SDL_SetRenderLogicalPresentation")]
     pub fn set_logical_size(
@@ -1289,6 +1615,21 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Gets the safe area for rendering within the current viewport.
+    ///
+    /// Some devices, such as TVs with overscan or phones with notches, have
+    /// parts of the screen that may not be visible. Important content like a
+    /// HUD should be kept inside the returned rectangle. Like the clip rect,
+    /// it is in render coordinates, relative to the current viewport.
+    #[doc(alias = "SDL_GetRenderSafeArea")]
+    pub fn safe_area(&self) -> Result<Rect, Error> {
+        let mut rect = mem::MaybeUninit::uninit();
+        result_from_bool(unsafe {
+            sys::render::SDL_GetRenderSafeArea(self.context.raw, rect.as_mut_ptr())
+        })?;
+        Ok(Rect::from_ll(unsafe { rect.assume_init() }))
+    }
+
     /// Gets the clip rectangle for the current target.
     #[doc(alias = "SDL_GetRenderClipRect")]
     pub fn clip_rect(&self) -> ClippingRect {
@@ -1309,6 +1650,15 @@ impl<T: RenderTarget> Canvas<T> {
     }
 
     /// Sets the drawing scale for rendering on the current target.
+    ///
+    /// The coordinates of all subsequent drawing operations are multiplied by
+    /// these factors; the draw color and clip rect are unaffected. This is
+    /// independent of `set_logical_size`: when both are used, coordinates are
+    /// first scaled by this factor and the result is then mapped from the
+    /// logical size to the output.
+    ///
+    /// The scale is stored per render target, so it does not carry over into
+    /// `with_texture_canvas`.
     #[doc(alias = "SDL_SetRenderScale")]
     pub fn set_scale(&mut self, scale_x: f32, scale_y: f32) -> Result<(), Error> {
         let ret = unsafe { sys::render::SDL_SetRenderScale(self.context.raw, scale_x, scale_y) };
@@ -1420,8 +1770,13 @@ impl<T: RenderTarget> Canvas<T> {
 
     /// Draws some number of rectangles on the current rendering target.
     /// Errors if drawing fails for any reason (e.g. driver failure)
+    ///
+    /// All rectangles are submitted in a single call. An empty slice does nothing.
     #[doc(alias = "SDL_RenderRects")]
     pub fn draw_rects(&mut self, rects: &[FRect]) -> Result<(), Error> {
+        if rects.is_empty() {
+            return Ok(());
+        }
         let result = unsafe {
             sys::render::SDL_RenderRects(
                 self.context.raw,
@@ -1459,8 +1814,13 @@ impl<T: RenderTarget> Canvas<T> {
     /// Fills some number of rectangles on the current rendering target with
     /// the drawing color.
     /// Errors if drawing fails for any reason (e.g. driver failure)
+    ///
+    /// All rectangles are submitted in a single call. An empty slice does nothing.
     #[doc(alias = "SDL_RenderFillRects")]
     pub fn fill_rects(&mut self, rects: &[FRect]) -> Result<(), Error> {
+        if rects.is_empty() {
+            return Ok(());
+        }
         let result = unsafe {
             sys::render::SDL_RenderFillRects(
                 self.context.raw,
@@ -1543,23 +1903,45 @@ impl<T: RenderTarget> Canvas<T> {
         R2: Into<Option<FRect>>,
         P: Into<Option<FPoint>>,
     {
-        let flip = unsafe {
-            match (flip_horizontal, flip_vertical) {
-                (false, false) => SDL_FLIP_NONE,
-                (true, false) => SDL_FLIP_HORIZONTAL,
-                (false, true) => SDL_FLIP_VERTICAL,
-                (true, true) => transmute::<u32, sys::surface::SDL_FlipMode>(
-                    transmute::<sys::surface::SDL_FlipMode, u32>(SDL_FLIP_HORIZONTAL)
-                        | transmute::<sys::surface::SDL_FlipMode, u32>(SDL_FLIP_VERTICAL),
-                ),
-            }
-        };
+        self.copy_ex_f(
+            texture,
+            src.into(),
+            dst.into(),
+            angle,
+            center.into(),
+            FlipMode::from_flags(flip_horizontal, flip_vertical),
+        )
+    }
 
-        let src = src.into().map(|rect| rect.to_ll());
-        let dst = dst.into().map(|rect| rect.to_ll());
-        let center = center.into().map(|point| point.to_ll());
+    /// Copies a portion of the texture to the current rendering target,
+    /// rotating it by `angle` around `center` and mirroring it according to
+    /// `flip`.
+    ///
+    /// * If `src` is `None`, the entire texture is copied.
+    /// * If `dst` is `None`, the texture will be stretched to fill the entire
+    ///   rendering target.
+    /// * `angle` is in degrees; positive values rotate clockwise.
+    /// * `center` is the rotation pivot, relative to the top-left corner of
+    ///   `dst`. If `center` is `None`, rotation is done around the center of
+    ///   `dst`.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderTextureRotated")]
+    pub fn copy_ex_f(
+        &mut self,
+        texture: &Texture,
+        src: Option<FRect>,
+        dst: Option<FRect>,
+        angle: f64,
+        center: Option<FPoint>,
+        flip: FlipMode,
+    ) -> Result<(), Error> {
+        let src = src.map(|rect| rect.to_ll());
+        let dst = dst.map(|rect| rect.to_ll());
+        let center = center.map(|point| point.to_ll());
 
-        let ret = unsafe {
+        result_from_bool(unsafe {
             sys::render::SDL_RenderTextureRotated(
                 self.context.raw,
                 texture.raw,
@@ -1576,7 +1958,112 @@ impl<T: RenderTarget> Canvas<T> {
                     Some(ref point) => point,
                     None => ptr::null(),
                 },
-                flip,
+                flip.into(),
+            )
+        })
+    }
+
+    /// Renders a list of triangles from separate vertex attribute arrays,
+    /// optionally using a texture and indices into the vertex arrays.
+    ///
+    /// Each stride is the distance in bytes between the start of two
+    /// consecutive vertices in the corresponding slice, which allows reading
+    /// attributes straight out of an interleaved buffer:
+    ///
+    /// * `xy` holds two floats (x, y) per vertex.
+    /// * `color` holds one `FColor` per vertex.
+    /// * `uv` holds two normalized texture coordinates per vertex. It is only
+    ///   read when `texture` is `Some`, and may be empty otherwise.
+    ///
+    /// If `indices` is empty, vertices are drawn sequentially, three per
+    /// triangle.
+    ///
+    /// Errors if any stride or count would read past the end of its slice,
+    /// if a stride is not a multiple of 4, if an index is out of range, or
+    /// if drawing fails for any reason (e.g. driver failure).
+    #[doc(alias = "SDL_RenderGeometryRaw")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_geometry_raw(
+        &mut self,
+        texture: Option<&Texture>,
+        xy: &[f32],
+        xy_stride: usize,
+        color: &[FColor],
+        color_stride: usize,
+        uv: &[f32],
+        uv_stride: usize,
+        num_vertices: usize,
+        indices: &[i32],
+    ) -> Result<(), Error> {
+        fn validate<T>(
+            name: &str,
+            data: &[T],
+            stride: usize,
+            element_size: usize,
+            num_vertices: usize,
+        ) -> Result<c_int, Error> {
+            if !stride.is_multiple_of(4) {
+                return Err(Error(format!("{name} stride must be a multiple of 4")));
+            }
+            let needed = match num_vertices {
+                0 => Some(0),
+                n => stride
+                    .checked_mul(n - 1)
+                    .and_then(|offset| offset.checked_add(element_size)),
+            };
+            match needed {
+                Some(needed) if needed <= mem::size_of_val(data) => {}
+                _ => return Err(Error(format!("{name} buffer is too small"))),
+            }
+            c_int::try_from(stride).map_err(|_| Error(format!("{name} stride overflows")))
+        }
+
+        let vertex_count = c_int::try_from(num_vertices)
+            .map_err(|_| Error("Vertex count overflows".to_owned()))?;
+        let index_count = c_int::try_from(indices.len())
+            .map_err(|_| Error("Index count overflows".to_owned()))?;
+        if indices
+            .iter()
+            .any(|&index| index < 0 || index as usize >= num_vertices)
+        {
+            return Err(Error("Vertex index out of range".to_owned()));
+        }
+
+        let xy_stride = validate("xy", xy, xy_stride, 2 * mem::size_of::<f32>(), num_vertices)?;
+        let color_stride = validate(
+            "color",
+            color,
+            color_stride,
+            mem::size_of::<FColor>(),
+            num_vertices,
+        )?;
+        let (texture, uv, uv_stride) = match texture {
+            Some(texture) => (
+                texture.raw,
+                uv.as_ptr(),
+                validate("uv", uv, uv_stride, 2 * mem::size_of::<f32>(), num_vertices)?,
+            ),
+            None => (ptr::null_mut(), ptr::null(), 0),
+        };
+
+        let ret = unsafe {
+            sys::render::SDL_RenderGeometryRaw(
+                self.context.raw,
+                texture,
+                xy.as_ptr(),
+                xy_stride,
+                color.as_ptr() as *const sys::pixels::SDL_FColor,
+                color_stride,
+                uv,
+                uv_stride,
+                vertex_count,
+                if indices.is_empty() {
+                    ptr::null()
+                } else {
+                    indices.as_ptr() as *const libc::c_void
+                },
+                index_count,
+                mem::size_of::<i32>() as c_int,
             )
         };
 
@@ -1757,6 +2244,7 @@ impl<T: RenderTarget> Canvas<T> {
     }
 }
 
+/// The format, access and size of a texture, as returned by `Texture::query`.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct TextureQuery {
     pub format: pixels::PixelFormat,
@@ -2022,25 +2510,41 @@ impl InternalTexture {
     }
 
     pub fn get_width(&self) -> u32 {
-        unsafe {
+        let width = unsafe {
             sys::properties::SDL_GetNumberProperty(
                 self.get_properties(),
                 sys::render::SDL_PROP_TEXTURE_WIDTH_NUMBER,
                 0,
-            ) as u32
+            )
+        };
+        match width {
+            0 => self.get_size().0,
+            width => width as u32,
         }
     }
 
     pub fn get_height(&self) -> u32 {
-        unsafe {
+        let height = unsafe {
             sys::properties::SDL_GetNumberProperty(
                 self.get_properties(),
                 sys::render::SDL_PROP_TEXTURE_HEIGHT_NUMBER,
                 0,
-            ) as u32
+            )
+        };
+        match height {
+            0 => self.get_size().1,
+            height => height as u32,
         }
     }
 
+    /// Fallback for when the size properties are missing.
+    #[doc(alias = "SDL_GetTextureSize")]
+    fn get_size(&self) -> (u32, u32) {
+        let (mut width, mut height) = (0.0, 0.0);
+        unsafe { sys::render::SDL_GetTextureSize(self.raw, &mut width, &mut height) };
+        (width as u32, height as u32)
+    }
+
     #[doc(alias = "SDL_SetTextureColorMod")]
     pub fn set_color_mod(&mut self, red: u8, green: u8, blue: u8) {
         let ret = unsafe { sys::render::SDL_SetTextureColorMod(self.raw, red, green, blue) };
@@ -2428,8 +2932,12 @@ impl InternalTexture {
 
 #[cfg(not(feature = "unsafe_textures"))]
 impl Texture<'_> {
-    /// Gets the texture's internal properties.
+    /// Queries the format, access and size of the texture.
+    ///
+    /// This replaces `SDL_QueryTexture`, which was removed in SDL3; the values
+    /// are read from the texture properties.
     #[inline]
+    #[doc(alias = "SDL_GetTextureProperties")]
     pub fn query(&self) -> TextureQuery {
         let internal = InternalTexture { raw: self.raw };
         TextureQuery {
@@ -2663,8 +3171,12 @@ impl Texture<'_> {
 
 #[cfg(feature = "unsafe_textures")]
 impl Texture {
-    /// Gets the texture's internal properties.
+    /// Queries the format, access and size of the texture.
+    ///
+    /// This replaces `SDL_QueryTexture`, which was removed in SDL3; the values
+    /// are read from the texture properties.
     #[inline]
+    #[doc(alias = "SDL_GetTextureProperties")]
     pub fn query(&self) -> TextureQuery {
         let internal = InternalTexture { raw: self.raw };
         TextureQuery {
@@ -2857,7 +3369,11 @@ impl Iterator for DriverIterator {
 
 impl ExactSizeIterator for DriverIterator {}
 
-/// Gets an iterator of all render drivers compiled into the SDL2 library.
+/// Gets an iterator of the names of all render drivers compiled into the SDL3
+/// library, in the order SDL tries them.
+///
+/// Any of these names can be passed to `create_renderer` to request a specific
+/// driver.
 #[inline]
 #[doc(alias = "SDL_GetNumRenderDrivers")]
 pub fn drivers() -> DriverIterator {
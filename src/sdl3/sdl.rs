@@ -348,8 +348,13 @@ impl Drop for EventPump {
 }
 
 /// Get platform name
+///
+/// Returns the name of the platform SDL was compiled for, such as `"Windows"`,
+/// `"macOS"`, `"Linux"`, `"iOS"` or `"Android"`. Returns `"Unknown"` if SDL
+/// doesn't recognize the platform.
 #[inline]
 #[doc(alias = "SDL_GetPlatform")]
+#[doc(alias = "platform")]
 pub fn get_platform() -> &'static str {
     unsafe {
         CStr::from_ptr(sys::platform::SDL_GetPlatform())
@@ -358,6 +363,95 @@ pub fn get_platform() -> &'static str {
     }
 }
 
+/// Keys of the application metadata, see `set_app_metadata_property`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AppMetadataProperty {
+    /// The human-readable name of the application.
+    Name,
+    /// The version of the application, in any format.
+    Version,
+    /// A unique identifier in reverse-domain format, like "com.example.mygame".
+    /// Used by desktop compositors (e.g. as the Wayland app id).
+    Identifier,
+    /// The human-readable name of the creator of the application.
+    Creator,
+    /// A one-line copyright notice.
+    Copyright,
+    /// A URL to the application on the web.
+    Url,
+    /// The type of application: "game", "mediaplayer" or "application".
+    Type,
+}
+
+impl AppMetadataProperty {
+    fn key(self) -> *const c_char {
+        use sys::init::*;
+        match self {
+            AppMetadataProperty::Name => SDL_PROP_APP_METADATA_NAME_STRING,
+            AppMetadataProperty::Version => SDL_PROP_APP_METADATA_VERSION_STRING,
+            AppMetadataProperty::Identifier => SDL_PROP_APP_METADATA_IDENTIFIER_STRING,
+            AppMetadataProperty::Creator => SDL_PROP_APP_METADATA_CREATOR_STRING,
+            AppMetadataProperty::Copyright => SDL_PROP_APP_METADATA_COPYRIGHT_STRING,
+            AppMetadataProperty::Url => SDL_PROP_APP_METADATA_URL_STRING,
+            AppMetadataProperty::Type => SDL_PROP_APP_METADATA_TYPE_STRING,
+        }
+    }
+}
+
+fn metadata_cstring(value: &str) -> Result<CString, Error> {
+    CString::new(value).map_err(|e| Error(e.to_string()))
+}
+
+/// Specifies basic metadata about the application.
+///
+/// This should be called as early as possible, ideally before `init()`.
+///
+/// # Example
+/// ```no_run
+/// sdl3::set_app_metadata("My Game", "1.0.0", "com.example.mygame").unwrap();
+/// let sdl_context = sdl3::init().unwrap();
+/// ```
+#[doc(alias = "SDL_SetAppMetadata")]
+pub fn set_app_metadata(name: &str, version: &str, identifier: &str) -> Result<(), Error> {
+    let name = metadata_cstring(name)?;
+    let version = metadata_cstring(version)?;
+    let identifier = metadata_cstring(identifier)?;
+    result_from_bool(unsafe {
+        sys::init::SDL_SetAppMetadata(name.as_ptr(), version.as_ptr(), identifier.as_ptr())
+    })
+}
+
+/// Specifies a single piece of metadata about the application.
+///
+/// Passing `None` as value clears the property.
+#[doc(alias = "SDL_SetAppMetadataProperty")]
+pub fn set_app_metadata_property(
+    property: AppMetadataProperty,
+    value: Option<&str>,
+) -> Result<(), Error> {
+    let value = value.map(metadata_cstring).transpose()?;
+    result_from_bool(unsafe {
+        sys::init::SDL_SetAppMetadataProperty(
+            property.key(),
+            value.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+        )
+    })
+}
+
+/// Gets a piece of metadata about the application, including defaults
+/// provided by SDL.
+#[doc(alias = "SDL_GetAppMetadataProperty")]
+pub fn get_app_metadata_property(property: AppMetadataProperty) -> Option<String> {
+    unsafe {
+        let value = sys::init::SDL_GetAppMetadataProperty(property.key());
+        if value.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(value).to_string_lossy().into_owned())
+        }
+    }
+}
+
 /// Initializes the SDL library.
 /// This must be called before using any other SDL function.
 ///
@@ -373,11 +467,17 @@ pub fn get_platform() -> &'static str {
 /// // SDL_Quit() is called here as `sdl_context` is dropped.
 /// ```
 #[inline]
-#[doc(alias = "SDL_GetError")]
+#[doc(alias = "SDL_Init")]
 pub fn init() -> Result<Sdl, Error> {
     Sdl::new()
 }
 
+/// Returns the last error message set by SDL on the current thread.
+///
+/// SDL does not clear the message on success, so it is only meaningful right
+/// after a call that reported a failure. Prefer `result_from_bool` for
+/// `bool`-returning SDL calls.
+#[doc(alias = "SDL_GetError")]
 pub fn get_error() -> Error {
     unsafe {
         let err = sys::error::SDL_GetError();
@@ -385,6 +485,8 @@ pub fn get_error() -> Error {
     }
 }
 
+/// Sets the error message of the current thread, e.g. to add context before
+/// handing control back to code that reads `get_error()`.
 #[doc(alias = "SDL_SetError")]
 pub fn set_error(err: &str) -> Result<(), NulError> {
     let c_string = CString::new(err)?;
@@ -404,9 +506,28 @@ pub fn set_error(err: &str) -> Result<(), NulError> {
 //     }
 // }
 
+/// Clears the error message of the current thread.
 #[doc(alias = "SDL_ClearError")]
 pub fn clear_error() {
     unsafe {
         sys::error::SDL_ClearError();
     }
 }
+
+/// Converts the `bool` returned by a fallible SDL call into a `Result`.
+///
+/// The error message is read immediately on failure, so it belongs to the
+/// call that failed rather than to some later one.
+///
+/// ```no_run
+/// let ok = unsafe { sdl3::sys::video::SDL_EnableScreenSaver() };
+/// sdl3::result_from_bool(ok).unwrap();
+/// ```
+#[inline]
+pub fn result_from_bool(ok: bool) -> Result<(), Error> {
+    if ok {
+        Ok(())
+    } else {
+        Err(get_error())
+    }
+}
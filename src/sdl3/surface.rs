@@ -8,8 +8,9 @@ use crate::get_error;
 use crate::iostream::IOStream;
 use crate::pixels;
 use crate::rect::Rect;
-use crate::render::{BlendMode, Canvas};
+use crate::render::{BlendMode, Canvas, ScaleMode};
 use crate::render::{Texture, TextureCreator, TextureValueError};
+use crate::result_from_bool;
 use crate::sys;
 use crate::Error;
 use libc::c_int;
@@ -59,6 +60,26 @@ pub struct SurfaceRef {
     _raw: (),
 }
 
+/// Keeps a surface locked for as long as it is alive.
+struct SurfaceLock(*mut sys::surface::SDL_Surface);
+
+impl SurfaceLock {
+    #[doc(alias = "SDL_LockSurface")]
+    unsafe fn new(raw: *mut sys::surface::SDL_Surface) -> SurfaceLock {
+        if !sys::surface::SDL_LockSurface(raw) {
+            panic!("could not lock surface");
+        }
+        SurfaceLock(raw)
+    }
+}
+
+impl Drop for SurfaceLock {
+    #[doc(alias = "SDL_UnlockSurface")]
+    fn drop(&mut self) {
+        unsafe { sys::surface::SDL_UnlockSurface(self.0) }
+    }
+}
+
 impl AsRef<SurfaceRef> for SurfaceRef {
     fn as_ref(&self) -> &SurfaceRef {
         self
@@ -172,6 +193,11 @@ impl<'a> Surface<'a> {
     }
 
     /// Creates a new surface from an existing buffer, using a pixel format.
+    ///
+    /// The pixels are not copied: the surface borrows `data` for its whole lifetime.
+    /// `pitch` is the length of a row in bytes. An error is returned if `data` is too
+    /// short for `height` rows of `pitch` bytes, or if `pitch` is too small for `width`
+    /// pixels of `format`.
     pub fn from_data(
         data: &'a mut [u8],
         width: u32,
@@ -197,6 +223,12 @@ impl<'a> Surface<'a> {
                 Err(Error("Image is too large.".to_owned()))
             } else if pitch >= (1 << 31) {
                 Err(Error("Pitch is too large.".to_owned()))
+            } else if (pitch as u64) < (width as u64 * masks.bpp as u64).div_ceil(8) {
+                Err(Error("Pitch is too small for the width.".to_owned()))
+            } else if (data.len() as u64) < pitch as u64 * height as u64 {
+                Err(Error(
+                    "Buffer is too small for the given size and pitch.".to_owned(),
+                ))
             } else {
                 let raw = sys::surface::SDL_CreateSurfaceFrom(
                     width as c_int,
@@ -221,6 +253,22 @@ impl<'a> Surface<'a> {
         }
     }
 
+    /// Creates a new surface holding a copy of `data`, using a pixel format.
+    ///
+    /// Same as [`Surface::from_data`], but the returned surface owns its pixels, so
+    /// `data` does not need to outlive it.
+    pub fn from_data_copied(
+        data: &[u8],
+        width: u32,
+        height: u32,
+        pitch: u32,
+        format: pixels::PixelFormat,
+    ) -> Result<Surface<'static>, Error> {
+        let mut copy = data.to_vec();
+        let borrowed = Surface::from_data(&mut copy, width, height, pitch, format)?;
+        borrowed.duplicate()
+    }
+
     /// A convenience function for [`TextureCreator::create_texture_from_surface`].
     ///
     /// ```no_run
@@ -285,8 +333,12 @@ impl<'a> Surface<'a> {
         texture_creator.create_texture_from_surface(self)
     }
 
-    #[doc(alias = "SDL_LoadBMP_RW")]
-    pub fn load_bmp_rw(iostream: &mut IOStream) -> Result<Surface<'static>, Error> {
+    /// Loads a BMP image from a data stream.
+    ///
+    /// The stream is read from its current position and is not closed, so
+    /// any `IOStream` source (memory, a custom archive, ...) can be used.
+    #[doc(alias = "SDL_LoadBMP_IO")]
+    pub fn load_bmp_io(iostream: &mut IOStream) -> Result<Surface<'static>, Error> {
         let raw = unsafe { sys::surface::SDL_LoadBMP_IO(iostream.raw(), false) };
 
         if raw.is_null() {
@@ -296,9 +348,14 @@ impl<'a> Surface<'a> {
         }
     }
 
+    /// Same as [`Surface::load_bmp_io`].
+    pub fn load_bmp_rw(iostream: &mut IOStream) -> Result<Surface<'static>, Error> {
+        Surface::load_bmp_io(iostream)
+    }
+
     pub fn load_bmp<P: AsRef<Path>>(path: P) -> Result<Surface<'static>, Error> {
         let mut file = IOStream::from_file(path, "rb")?;
-        Surface::load_bmp_rw(&mut file)
+        Surface::load_bmp_io(&mut file)
     }
 
     /// Creates a Software Canvas to allow rendering in the Surface itself. This `Canvas` will
@@ -372,36 +429,36 @@ impl SurfaceRef {
     }
 
     /// Locks a surface so that the pixels can be directly accessed safely.
+    ///
+    /// The surface is unlocked again when `f` returns, or if it panics. Rows in
+    /// the buffer are [`pitch()`](#method.pitch) bytes apart. RLE accelerated
+    /// surfaces are decoded while locked, so this is the only safe way to read
+    /// their pixels.
     #[doc(alias = "SDL_LockSurface")]
     pub fn with_lock<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
         unsafe {
-            if !sys::surface::SDL_LockSurface(self.raw()) {
-                panic!("could not lock surface");
-            }
+            let _lock = SurfaceLock::new(self.raw());
 
             let raw_pixels = self.raw_ref().pixels as *const _;
             let len = self.raw_ref().pitch as usize * (self.raw_ref().h as usize);
             let pixels = ::std::slice::from_raw_parts(raw_pixels, len);
-            let rv = f(pixels);
-            sys::surface::SDL_UnlockSurface(self.raw());
-            rv
+            f(pixels)
         }
     }
 
     /// Locks a surface so that the pixels can be directly accessed safely.
+    ///
+    /// The surface is unlocked again when `f` returns, or if it panics. Rows in
+    /// the buffer are [`pitch()`](#method.pitch) bytes apart.
     #[doc(alias = "SDL_LockSurface")]
     pub fn with_lock_mut<R, F: FnOnce(&mut [u8]) -> R>(&mut self, f: F) -> R {
         unsafe {
-            if !sys::surface::SDL_LockSurface(self.raw()) {
-                panic!("could not lock surface");
-            }
+            let _lock = SurfaceLock::new(self.raw());
 
             let raw_pixels = self.raw_ref().pixels as *mut _;
             let len = self.raw_ref().pitch as usize * (self.raw_ref().h as usize);
             let pixels = ::std::slice::from_raw_parts_mut(raw_pixels, len);
-            let rv = f(pixels);
-            sys::surface::SDL_UnlockSurface(self.raw());
-            rv
+            f(pixels)
         }
     }
 
@@ -461,6 +518,22 @@ impl SurfaceRef {
         }
     }
 
+    /// Enables or disables RLE acceleration for the surface.
+    ///
+    /// RLE speeds up blitting of surfaces with a color key or alpha channel,
+    /// but the pixels of an RLE surface can only be accessed while it's locked,
+    /// see [`with_lock`](#method.with_lock).
+    #[doc(alias = "SDL_SetSurfaceRLE")]
+    pub fn set_rle(&mut self, enabled: bool) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::surface::SDL_SetSurfaceRLE(self.raw(), enabled) })
+    }
+
+    /// Returns whether the surface is RLE enabled.
+    #[doc(alias = "SDL_SurfaceHasRLE")]
+    pub fn has_rle(&self) -> bool {
+        unsafe { sys::surface::SDL_SurfaceHasRLE(self.raw()) }
+    }
+
     #[allow(non_snake_case)]
     #[doc(alias = "SDL_SetSurfaceRLE")]
     pub fn enable_RLE(&mut self) {
@@ -510,6 +583,10 @@ impl SurfaceRef {
         }
     }
 
+    /// Sets an additional color value multiplied into blit operations.
+    ///
+    /// When this surface is blitted, each source color channel is modulated
+    /// by `srcC = srcC * (color / 255)`, just like `Texture::set_color_mod`.
     #[doc(alias = "SDL_SetSurfaceColorMod")]
     pub fn set_color_mod(&mut self, color: pixels::Color) {
         let (r, g, b) = color.rgb();
@@ -521,6 +598,7 @@ impl SurfaceRef {
         }
     }
 
+    /// Gets the additional color value multiplied into blit operations.
     #[doc(alias = "SDL_GetSurfaceColorMod")]
     pub fn color_mod(&self) -> pixels::Color {
         let mut r = 0;
@@ -568,6 +646,11 @@ impl SurfaceRef {
         Ok(())
     }
 
+    /// Sets an additional alpha value multiplied into blit operations.
+    ///
+    /// When this surface is blitted, the source alpha is modulated by
+    /// `srcA = srcA * (alpha / 255)`, just like `Texture::set_alpha_mod`.
+    /// The blend mode must allow alpha for this to have a visible effect.
     #[doc(alias = "SDL_SetSurfaceAlphaMod")]
     pub fn set_alpha_mod(&mut self, alpha: u8) {
         let result = unsafe { sys::surface::SDL_SetSurfaceAlphaMod(self.raw(), alpha) };
@@ -578,6 +661,7 @@ impl SurfaceRef {
         }
     }
 
+    /// Gets the additional alpha value multiplied into blit operations.
     #[doc(alias = "SDL_GetSurfaceAlphaMod")]
     pub fn alpha_mod(&self) -> u8 {
         let mut alpha = 0;
@@ -590,6 +674,8 @@ impl SurfaceRef {
         }
     }
 
+    /// Sets the blend mode used for blit operations from this surface.
+    ///
     /// The function will fail if the blend mode is not supported by SDL.
     #[doc(alias = "SDL_SetSurfaceBlendMode")]
     pub fn set_blend_mode(&mut self, mode: BlendMode) -> Result<(), Error> {
@@ -601,6 +687,7 @@ impl SurfaceRef {
         }
     }
 
+    /// Gets the blend mode used for blit operations from this surface.
     #[doc(alias = "SDL_GetSurfaceBlendMode")]
     pub fn blend_mode(&self) -> BlendMode {
         let mut mode = SDL_BLENDMODE_NONE;
@@ -615,7 +702,12 @@ impl SurfaceRef {
 
     /// Sets the clip rectangle for the surface.
     ///
-    /// If the rectangle is `None`, clipping will be disabled.
+    /// Blits into this surface are trimmed to the clip rectangle, which is
+    /// itself intersected with the surface bounds. If the rectangle is `None`,
+    /// the clip rectangle is reset to the full surface.
+    ///
+    /// Returns `false` if the rectangle does not intersect the surface, in
+    /// which case nothing will be drawn by subsequent blits.
     #[doc(alias = "SDL_SetSurfaceClipRect")]
     pub fn set_clip_rect<R>(&mut self, rect: R) -> bool
     where
@@ -635,7 +727,8 @@ impl SurfaceRef {
 
     /// Gets the clip rectangle for the surface.
     ///
-    /// Returns `None` if clipping is disabled.
+    /// Returns `None` if the clip rectangle is empty, i.e. the last rectangle
+    /// passed to [`SurfaceRef::set_clip_rect`] did not intersect the surface.
     #[doc(alias = "SDL_GetSurfaceClipRect")]
     pub fn clip_rect(&self) -> Option<Rect> {
         let mut raw = mem::MaybeUninit::uninit();
@@ -675,6 +768,134 @@ impl SurfaceRef {
         }
     }
 
+    /// Creates an independent deep copy of the surface.
+    ///
+    /// The copy has the same size, pixel format and pixels, and SDL also
+    /// copies the palette, color key, color/alpha modulation and blend mode,
+    /// so it can be modified without affecting the original.
+    #[doc(alias = "SDL_DuplicateSurface")]
+    pub fn duplicate(&self) -> Result<Surface<'static>, Error> {
+        let surface_ptr = unsafe { sys::surface::SDL_DuplicateSurface(self.raw()) };
+
+        if surface_ptr.is_null() {
+            Err(get_error())
+        } else {
+            unsafe { Ok(Surface::from_ll(surface_ptr)) }
+        }
+    }
+
+    /// Creates a new surface with the contents of this one scaled to `width` x `height`.
+    ///
+    /// The new surface has the same pixel format, and keeps the alpha channel,
+    /// color key and blend settings of the original. Unlike `blit_scaled()`
+    /// the destination surface is allocated for you.
+    #[doc(alias = "SDL_ScaleSurface")]
+    pub fn scaled(
+        &self,
+        width: u32,
+        height: u32,
+        mode: ScaleMode,
+    ) -> Result<Surface<'static>, Error> {
+        if width >= (1 << 31) || height >= (1 << 31) {
+            return Err(Error("Image is too large.".to_owned()));
+        }
+
+        let surface_ptr = unsafe {
+            sys::surface::SDL_ScaleSurface(self.raw(), width as c_int, height as c_int, mode.into())
+        };
+
+        if surface_ptr.is_null() {
+            Err(get_error())
+        } else {
+            unsafe { Ok(Surface::from_ll(surface_ptr)) }
+        }
+    }
+
+    /// Copies the `src_rect` region of this surface into a new, independent surface
+    /// of the same size as `src_rect` and the same pixel format.
+    ///
+    /// The pixels are copied as-is rather than blended, and the color key,
+    /// color/alpha modulation and blend mode are carried over, so transparency
+    /// behaves the same when the new surface is blitted. Paletted surfaces get
+    /// their own copy of the palette. This is
+    /// handy for slicing a sprite atlas into individual frames.
+    ///
+    /// Fails if `src_rect` is not fully inside the surface, or if the format
+    /// has less than 8 bits per pixel.
+    pub fn extract(&self, src_rect: Rect) -> Result<Surface<'static>, Error> {
+        if !self.rect().contains_rect(src_rect) {
+            return Err(Error(
+                "Source rect is out of the surface bounds.".to_owned(),
+            ));
+        }
+        let format = self.raw_ref().format;
+        let bytes_per_pixel = sys::pixels::SDL_BYTESPERPIXEL(format) as usize;
+        if bytes_per_pixel == 0 || sys::pixels::SDL_ISPIXELFORMAT_FOURCC(format) {
+            return Err(Error(
+                "Extracting from this pixel format is not supported.".to_owned(),
+            ));
+        }
+
+        let raw = unsafe {
+            sys::surface::SDL_CreateSurface(
+                src_rect.width() as c_int,
+                src_rect.height() as c_int,
+                format,
+            )
+        };
+        if raw.is_null() {
+            return Err(get_error());
+        }
+        let mut dst = unsafe { Surface::from_ll(raw) };
+
+        let src_pitch = self.pitch() as usize;
+        let dst_pitch = dst.pitch() as usize;
+        let row_len = src_rect.width() as usize * bytes_per_pixel;
+        let x_offset = src_rect.x() as usize * bytes_per_pixel;
+        self.with_lock(|src_pixels| {
+            dst.with_lock_mut(|dst_pixels| {
+                for row in 0..src_rect.height() as usize {
+                    let src_start = (src_rect.y() as usize + row) * src_pitch + x_offset;
+                    let dst_start = row * dst_pitch;
+                    dst_pixels[dst_start..dst_start + row_len]
+                        .copy_from_slice(&src_pixels[src_start..src_start + row_len]);
+                }
+            })
+        });
+
+        unsafe {
+            let palette = sys::surface::SDL_GetSurfacePalette(self.raw());
+            if !palette.is_null() {
+                // Give the new surface its own copy, so changing one palette doesn't affect
+                // the other surface.
+                let ncolors = (*palette).ncolors;
+                let copy = sys::pixels::SDL_CreatePalette(ncolors);
+                if copy.is_null() {
+                    return Err(get_error());
+                }
+                let result =
+                    if sys::pixels::SDL_SetPaletteColors(copy, (*palette).colors, 0, ncolors) {
+                        result_from_bool(sys::surface::SDL_SetSurfacePalette(dst.raw(), copy))
+                    } else {
+                        Err(get_error())
+                    };
+                // The surface holds its own reference to the palette.
+                sys::pixels::SDL_DestroyPalette(copy);
+                result?;
+            }
+            if sys::surface::SDL_SurfaceHasColorKey(self.raw()) {
+                let mut key = 0;
+                result_from_bool(sys::surface::SDL_GetSurfaceColorKey(self.raw(), &mut key))?;
+                result_from_bool(sys::surface::SDL_SetSurfaceColorKey(dst.raw(), true, key))?;
+            }
+        }
+        dst.set_color_mod(self.color_mod());
+        dst.set_alpha_mod(self.alpha_mod());
+        dst.set_blend_mode(self.blend_mode())?;
+
+        Ok(dst)
+    }
+
     /// Performs surface blitting (surface copying).
     ///
     /// Returns the final blit rectangle, if a `dst_rect` was provided.
@@ -713,6 +934,35 @@ impl SurfaceRef {
         }
     }
 
+    /// Performs surface blitting with `mode` as the blend mode of this surface.
+    ///
+    /// The previous blend mode is restored afterwards, also when the blit fails, so
+    /// blits with different modes can be mixed without resetting the mode manually.
+    /// If both the blit and the restore fail, the blit error is returned.
+    ///
+    /// Returns the final blit rectangle, if a `dst_rect` was provided.
+    #[doc(alias = "SDL_BlitSurface")]
+    pub fn blit_with_mode<R1, R2>(
+        &mut self,
+        src_rect: R1,
+        dst: &mut SurfaceRef,
+        dst_rect: R2,
+        mode: BlendMode,
+    ) -> Result<Option<Rect>, Error>
+    where
+        R1: Into<Option<Rect>>,
+        R2: Into<Option<Rect>>,
+    {
+        let previous = self.blend_mode();
+        self.set_blend_mode(mode)?;
+        let result = self.blit(src_rect, dst, dst_rect);
+        let restored = self.set_blend_mode(previous);
+        // A failed blit is the more useful error, so it wins over a failed restore.
+        let rect = result?;
+        restored?;
+        Ok(rect)
+    }
+
     /// Performs low-level surface blitting.
     ///
     /// Unless you know what you're doing, use `blit()` instead, which will clip the input rectangles.
@@ -1,6 +1,7 @@
 use crate::sys;
 use libc::c_void;
 use std::ptr::NonNull;
+use std::time::Duration;
 
 /// Constructs a new timer using the boxed closure `callback`.
 ///
@@ -31,6 +32,9 @@ pub fn add_timer(delay: u32, callback: TimerCallback) -> Timer {
 
 /// Gets the number of milliseconds elapsed since the timer subsystem was initialized.
 ///
+/// SDL3 has no separate timer subsystem; timing is available as soon as the library is
+/// initialized, so this is a free function rather than a method on a subsystem.
+///
 /// It's recommended to use another library for timekeeping, such as `time`.
 #[doc(alias = "SDL_GetTicks")]
 pub fn ticks() -> u64 {
@@ -55,6 +59,72 @@ pub fn performance_frequency() -> u64 {
     unsafe { sys::timer::SDL_GetPerformanceFrequency() }
 }
 
+/// Measures the time between frames using the high resolution performance counter.
+///
+/// # Example
+/// ```no_run
+/// use sdl3::timer::FrameClock;
+///
+/// let mut clock = FrameClock::new();
+/// loop {
+///     let dt = clock.tick();
+///     // update(dt); render();
+/// #   break;
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FrameClock {
+    last: Option<u64>,
+    frequency: u64,
+    initial_delta: Duration,
+}
+
+impl FrameClock {
+    /// Creates a clock whose first [`FrameClock::tick`] returns a zero delta.
+    pub fn new() -> FrameClock {
+        FrameClock::with_initial_delta(Duration::ZERO)
+    }
+
+    /// Creates a clock whose first [`FrameClock::tick`] returns `initial_delta`, e.g. the
+    /// nominal frame time, so the first update is not a no-op.
+    pub fn with_initial_delta(initial_delta: Duration) -> FrameClock {
+        FrameClock {
+            last: None,
+            frequency: performance_frequency().max(1),
+            initial_delta,
+        }
+    }
+
+    /// Returns the time elapsed since the previous call, or the initial delta on the first call.
+    ///
+    /// The performance counter is read with nanosecond precision and wraparound is handled.
+    pub fn tick(&mut self) -> Duration {
+        let now = performance_counter();
+        let delta = match self.last {
+            Some(last) => {
+                let elapsed = now.wrapping_sub(last) as u128;
+                let nanos = elapsed * 1_000_000_000 / self.frequency as u128;
+                Duration::from_nanos(nanos.min(u64::MAX as u128) as u64)
+            }
+            None => self.initial_delta,
+        };
+        self.last = Some(now);
+        delta
+    }
+
+    /// Forgets the previous tick, so the next [`FrameClock::tick`] returns the initial delta
+    /// again. Useful after a pause to avoid a huge delta.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+impl Default for FrameClock {
+    fn default() -> FrameClock {
+        FrameClock::new()
+    }
+}
+
 /// Type alias for the timer callback function.
 pub type TimerCallback = Box<dyn FnMut() -> u32 + Send + 'static>;
 
@@ -108,7 +178,7 @@ mod test {
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
-    use crate::timer::add_timer;
+    use crate::timer::{add_timer, FrameClock};
 
     #[test]
     fn test_timer_runs_multiple_times() {
@@ -188,4 +258,17 @@ mod test {
         let num = local_num.lock().unwrap();
         assert_eq!(*num, 2);
     }
+
+    #[test]
+    fn test_frame_clock_measures_delta() {
+        let _sdl_context = crate::sdl::init().unwrap();
+
+        let mut clock = FrameClock::with_initial_delta(Duration::from_millis(16));
+        assert_eq!(clock.tick(), Duration::from_millis(16));
+
+        std::thread::sleep(Duration::from_millis(20));
+        let delta = clock.tick();
+        assert!(delta >= Duration::from_millis(20));
+        assert!(delta < Duration::from_secs(2));
+    }
 }
@@ -38,12 +38,17 @@ impl error::Error for OpenUrlError {
 /// Opens a URL/URI in the default system-provided application.
 ///
 /// This will most likely open a web browser for http:// and https:// links,
-/// the default handler application for file:// links, but this varies
-/// between platforms and is not supported on all of them.
+/// the mail client for mailto: links and the default handler application for
+/// file:// links, but this varies between platforms and is not supported on
+/// all of them.
 /// It might also cause your window to lose focus, or pause your process on mobile.
 ///
+/// The call is asynchronous: it returns once the request has been handed to
+/// the system and does not wait for the handler application to start.
 /// There is no way to tell if the system successfully opened the provided URL,
 /// an `Ok` result only means that something was launched to try to handle it.
+/// If the platform cannot handle URLs at all, `OpenUrlError::SdlError` is
+/// returned.
 ///
 /// # Examples
 ///
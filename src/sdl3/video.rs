@@ -2,8 +2,9 @@ use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
 use crate::pixels::PixelFormat;
 use crate::properties::{Properties, PropertiesError};
-use crate::rect::Rect;
+use crate::rect::{Point, Rect};
 use crate::render::{create_renderer, WindowCanvas};
+use crate::result_from_bool;
 use crate::surface::SurfaceRef;
 use crate::Error;
 use crate::EventPump;
@@ -16,10 +17,11 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops::{Deref, DerefMut};
 use std::ptr::{null, null_mut};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::{fmt, mem, ptr};
 use sys::properties::{
-    SDL_CreateProperties, SDL_DestroyProperties, SDL_SetNumberProperty, SDL_SetStringProperty,
+    SDL_CreateProperties, SDL_DestroyProperties, SDL_SetBooleanProperty, SDL_SetNumberProperty,
+    SDL_SetPointerProperty, SDL_SetStringProperty,
 };
 use sys::stdinc::{SDL_FunctionPointer, SDL_free, Uint32, Uint64};
 use sys::video::{
@@ -545,6 +547,19 @@ pub struct WindowContext {
     subsystem: VideoSubsystem,
     raw: *mut sys::video::SDL_Window,
     pub(crate) metal_view: sys::metal::SDL_MetalView,
+    /// Keeps the parent set with `Window::set_parent` alive, since SDL destroys
+    /// child windows together with their parent.
+    parent: Mutex<Option<Arc<WindowContext>>>,
+    /// State for `Window::set_relative_mouse_warp`.
+    pub(crate) relative_mouse_restore: Mutex<RelativeMouseRestore>,
+}
+
+/// Whether to restore the cursor position when leaving relative mouse mode, and the
+/// position saved when entering it.
+#[derive(Default)]
+pub(crate) struct RelativeMouseRestore {
+    pub(crate) enabled: bool,
+    pub(crate) position: Option<(f32, f32)>,
 }
 
 impl Drop for WindowContext {
@@ -572,6 +587,8 @@ impl WindowContext {
             subsystem: subsystem.clone(),
             raw,
             metal_view,
+            parent: Mutex::new(None),
+            relative_mouse_restore: Mutex::new(RelativeMouseRestore::default()),
         }
     }
 }
@@ -669,6 +686,38 @@ impl FlashOperation {
     }
 }
 
+bitflags! {
+    /// A snapshot of a window's state, as returned by [`Window::flags`].
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct WindowFlags: u64 {
+        const FULLSCREEN = sys::video::SDL_WINDOW_FULLSCREEN;
+        const OPENGL = sys::video::SDL_WINDOW_OPENGL;
+        const OCCLUDED = sys::video::SDL_WINDOW_OCCLUDED;
+        const HIDDEN = sys::video::SDL_WINDOW_HIDDEN;
+        const BORDERLESS = sys::video::SDL_WINDOW_BORDERLESS;
+        const RESIZABLE = sys::video::SDL_WINDOW_RESIZABLE;
+        const MINIMIZED = sys::video::SDL_WINDOW_MINIMIZED;
+        const MAXIMIZED = sys::video::SDL_WINDOW_MAXIMIZED;
+        const MOUSE_GRABBED = sys::video::SDL_WINDOW_MOUSE_GRABBED;
+        const INPUT_FOCUS = sys::video::SDL_WINDOW_INPUT_FOCUS;
+        const MOUSE_FOCUS = sys::video::SDL_WINDOW_MOUSE_FOCUS;
+        const EXTERNAL = sys::video::SDL_WINDOW_EXTERNAL;
+        const MODAL = sys::video::SDL_WINDOW_MODAL;
+        const HIGH_PIXEL_DENSITY = sys::video::SDL_WINDOW_HIGH_PIXEL_DENSITY;
+        const MOUSE_CAPTURE = sys::video::SDL_WINDOW_MOUSE_CAPTURE;
+        const MOUSE_RELATIVE_MODE = sys::video::SDL_WINDOW_MOUSE_RELATIVE_MODE;
+        const ALWAYS_ON_TOP = sys::video::SDL_WINDOW_ALWAYS_ON_TOP;
+        const UTILITY = sys::video::SDL_WINDOW_UTILITY;
+        const TOOLTIP = sys::video::SDL_WINDOW_TOOLTIP;
+        const POPUP_MENU = sys::video::SDL_WINDOW_POPUP_MENU;
+        const KEYBOARD_GRABBED = sys::video::SDL_WINDOW_KEYBOARD_GRABBED;
+        const VULKAN = sys::video::SDL_WINDOW_VULKAN;
+        const METAL = sys::video::SDL_WINDOW_METAL;
+        const TRANSPARENT = sys::video::SDL_WINDOW_TRANSPARENT;
+        const NOT_FOCUSABLE = sys::video::SDL_WINDOW_NOT_FOCUSABLE;
+    }
+}
+
 /// Represents the "shell" of a `Window`.
 ///
 /// You can set get and set many of the `SDL_Window` properties (i.e., border, size, `PixelFormat`, etc)
@@ -684,6 +733,12 @@ pub struct Window {
     context: Arc<WindowContext>, // Arc may not be needed, added because wgpu expects Window to be send/sync, though even with Arc this technically still isn't send/sync
 }
 
+impl fmt::Debug for Window {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Window").field("id", &self.id()).finish()
+    }
+}
+
 impl From<WindowContext> for Window {
     fn from(context: WindowContext) -> Window {
         Window {
@@ -694,6 +749,104 @@ impl From<WindowContext> for Window {
 
 impl_raw_accessors!((GLContext, sys::video::SDL_GLContext));
 
+/// A non-owning handle to a window, obtained through [`VideoSubsystem::window_from_id`].
+///
+/// Only the window id is stored: every call looks the window up again and returns
+/// an error once it has been destroyed, so the handle can safely outlive the
+/// [`Window`] that owns the window. Dropping it never destroys the window.
+#[derive(Clone)]
+pub struct WindowRef {
+    id: u32,
+    _subsystem: VideoSubsystem,
+}
+
+impl WindowRef {
+    /// The id of the window, as carried in window related events.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns `true` while the window still exists.
+    pub fn exists(&self) -> bool {
+        self.raw().is_ok()
+    }
+
+    #[doc(alias = "SDL_GetWindowFromID")]
+    fn raw(&self) -> Result<*mut sys::video::SDL_Window, Error> {
+        let raw = unsafe { sys::video::SDL_GetWindowFromID(self.id) };
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(raw)
+        }
+    }
+
+    #[doc(alias = "SDL_GetWindowTitle")]
+    pub fn title(&self) -> Result<String, Error> {
+        let raw = self.raw()?;
+        let buf = unsafe { sys::video::SDL_GetWindowTitle(raw) };
+        Ok(unsafe { CStr::from_ptr(buf) }
+            .to_string_lossy()
+            .into_owned())
+    }
+
+    #[doc(alias = "SDL_GetWindowFlags")]
+    pub fn flags(&self) -> Result<WindowFlags, Error> {
+        let raw = self.raw()?;
+        Ok(WindowFlags::from_bits_retain(unsafe {
+            sys::video::SDL_GetWindowFlags(raw)
+        }))
+    }
+
+    #[doc(alias = "SDL_GetWindowPosition")]
+    pub fn position(&self) -> Result<(i32, i32), Error> {
+        let raw = self.raw()?;
+        let mut x: c_int = 0;
+        let mut y: c_int = 0;
+        result_from_bool(unsafe { sys::video::SDL_GetWindowPosition(raw, &mut x, &mut y) })?;
+        Ok((x as i32, y as i32))
+    }
+
+    #[doc(alias = "SDL_GetWindowSize")]
+    pub fn size(&self) -> Result<(u32, u32), Error> {
+        let raw = self.raw()?;
+        let mut w: c_int = 0;
+        let mut h: c_int = 0;
+        result_from_bool(unsafe { sys::video::SDL_GetWindowSize(raw, &mut w, &mut h) })?;
+        Ok((w as u32, h as u32))
+    }
+
+    #[doc(alias = "SDL_ShowWindow")]
+    pub fn show(&self) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_ShowWindow(self.raw()?) })
+    }
+
+    #[doc(alias = "SDL_HideWindow")]
+    pub fn hide(&self) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_HideWindow(self.raw()?) })
+    }
+
+    #[doc(alias = "SDL_RaiseWindow")]
+    pub fn raise(&self) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_RaiseWindow(self.raw()?) })
+    }
+
+    #[doc(alias = "SDL_MinimizeWindow")]
+    pub fn minimize(&self) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_MinimizeWindow(self.raw()?) })
+    }
+
+    #[doc(alias = "SDL_MaximizeWindow")]
+    pub fn maximize(&self) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_MaximizeWindow(self.raw()?) })
+    }
+
+    #[doc(alias = "SDL_RestoreWindow")]
+    pub fn restore(&self) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_RestoreWindow(self.raw()?) })
+    }
+}
+
 /// System theme.
 pub enum SystemTheme {
     /// Unknown system theme.
@@ -849,6 +1002,21 @@ impl Display {
         unsafe { sys::video::SDL_GetNaturalDisplayOrientation(self.id) }
     }
 
+    /// Returns the current orientation of the display.
+    ///
+    /// Changes are reported through `DisplayEvent::Orientation` events.
+    #[doc(alias = "SDL_GetCurrentDisplayOrientation")]
+    pub fn orientation(&self) -> Orientation {
+        Orientation::from_ll(self.get_orientation())
+    }
+
+    /// Returns the orientation the display has when the device is held in
+    /// its default position, e.g. `Portrait` for most phones.
+    #[doc(alias = "SDL_GetNaturalDisplayOrientation")]
+    pub fn natural_orientation(&self) -> Orientation {
+        Orientation::from_ll(self.get_natural_orientation())
+    }
+
     #[doc(alias = "SDL_GetDisplayContentScale")]
     pub fn get_content_scale(&self) -> Result<f32, Error> {
         let value = unsafe { sys::video::SDL_GetDisplayContentScale(self.id) };
@@ -906,6 +1074,29 @@ impl VideoSubsystem {
         PopupWindowBuilder::new(self, window, width, height)
     }
 
+    /// Looks up a window by the id carried in window related events.
+    ///
+    /// Returns `None` if no window with that id exists. The returned [`WindowRef`]
+    /// doesn't own the window, so dropping it won't close a window you didn't
+    /// create, and its methods return an error once the window is gone.
+    #[doc(alias = "SDL_GetWindowFromID")]
+    pub fn window_from_id(&self, id: u32) -> Option<WindowRef> {
+        let raw = unsafe { sys::video::SDL_GetWindowFromID(id) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(WindowRef {
+                id,
+                _subsystem: self.clone(),
+            })
+        }
+    }
+
+    /// Returns the name of the video driver in use, such as `"wayland"`.
+    ///
+    /// This is `"dummy"` when the dummy driver was selected (for example
+    /// through the `SDL_VIDEO_DRIVER` hint). The names match the
+    /// ones yielded by [`drivers`].
     #[doc(alias = "SDL_GetCurrentVideoDriver")]
     pub fn current_video_driver(&self) -> &'static str {
         use std::str;
@@ -958,6 +1149,35 @@ impl VideoSubsystem {
         }
     }
 
+    /// Get the display containing `point`, in global screen coordinates.
+    ///
+    /// Fails if the point is not on any display. Combined with
+    /// [`Display::get_usable_bounds`] this can be used to keep popups placed
+    /// at the mouse cursor fully on screen.
+    #[doc(alias = "SDL_GetDisplayForPoint")]
+    pub fn display_for_point(&self, point: Point) -> Result<Display, Error> {
+        let id = unsafe { sys::video::SDL_GetDisplayForPoint(point.raw()) };
+        if id == 0 {
+            Err(get_error())
+        } else {
+            Ok(Display::from_ll(id))
+        }
+    }
+
+    /// Get the display that overlaps the most with `rect`, in global screen
+    /// coordinates.
+    ///
+    /// Fails if the rectangle doesn't intersect any display.
+    #[doc(alias = "SDL_GetDisplayForRect")]
+    pub fn display_for_rect(&self, rect: Rect) -> Result<Display, Error> {
+        let id = unsafe { sys::video::SDL_GetDisplayForRect(rect.raw()) };
+        if id == 0 {
+            Err(get_error())
+        } else {
+            Ok(Display::from_ll(id))
+        }
+    }
+
     #[doc(alias = "SDL_ScreenSaverEnabled")]
     pub fn is_screen_saver_enabled(&self) -> bool {
         unsafe { sys::video::SDL_ScreenSaverEnabled() }
@@ -1205,6 +1425,8 @@ pub struct WindowBuilder {
     y: WindowPos,
     window_flags: u32,
     create_metal_view: bool,
+    external_graphics_context: bool,
+    parent: Option<Window>,
     /// The window builder cannot be built on a non-main thread, so prevent cross-threaded moves and references.
     /// `!Send` and `!Sync`,
     subsystem: VideoSubsystem,
@@ -1222,6 +1444,8 @@ impl WindowBuilder {
             window_flags: 0,
             subsystem: v.clone(),
             create_metal_view: false,
+            external_graphics_context: false,
+            parent: None,
         }
     }
 
@@ -1277,6 +1501,20 @@ impl WindowBuilder {
             );
             let flags_cstr = CString::new("SDL.window.create.flags").unwrap();
             SDL_SetNumberProperty(props, flags_cstr.as_ptr(), self.window_flags.into());
+            if self.external_graphics_context {
+                SDL_SetBooleanProperty(
+                    props,
+                    sys::video::SDL_PROP_WINDOW_CREATE_EXTERNAL_GRAPHICS_CONTEXT_BOOLEAN,
+                    true,
+                );
+            }
+            if let Some(parent) = &self.parent {
+                SDL_SetPointerProperty(
+                    props,
+                    sys::video::SDL_PROP_WINDOW_CREATE_PARENT_POINTER,
+                    parent.raw() as *mut c_void,
+                );
+            }
 
             let raw = sys::video::SDL_CreateWindowWithProperties(props);
             SDL_DestroyProperties(props);
@@ -1293,9 +1531,10 @@ impl WindowBuilder {
             if raw.is_null() {
                 Err(SdlError(get_error()))
             } else {
-                {
-                    Ok(Window::from_ll(self.subsystem.clone(), raw, metal_view))
-                }
+                let window = Window::from_ll(self.subsystem.clone(), raw, metal_view);
+                *window.context.parent.lock().unwrap() =
+                    self.parent.as_ref().map(|parent| parent.context());
+                Ok(window)
             }
         }
     }
@@ -1393,6 +1632,52 @@ impl WindowBuilder {
         self.create_metal_view = true;
         self
     }
+
+    /// Window with a transparent framebuffer, e.g. for overlays.
+    pub fn transparent(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_TRANSPARENT as u32;
+        self
+    }
+
+    /// Window that is always kept above other windows.
+    pub fn always_on_top(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_ALWAYS_ON_TOP as u32;
+        self
+    }
+
+    /// Window that should be treated as a utility window, not showing in the
+    /// task bar and window list.
+    pub fn utility(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_UTILITY as u32;
+        self
+    }
+
+    /// Window that doesn't receive input focus when shown or clicked.
+    pub fn not_focusable(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_NOT_FOCUSABLE as u32;
+        self
+    }
+
+    /// The application will manage the graphics context of the window
+    /// (e.g. an OpenGL or Vulkan context created by another library) instead
+    /// of SDL.
+    pub fn external_graphics_context(&mut self) -> &mut WindowBuilder {
+        self.external_graphics_context = true;
+        self
+    }
+
+    /// Creates the window as a child of `parent`, see `Window::set_parent`.
+    /// Combine with `modal()` for a modal dialog.
+    pub fn parent(&mut self, parent: &Window) -> &mut WindowBuilder {
+        self.parent = Some(parent.clone());
+        self
+    }
+
+    /// Window is modal to its parent. Requires `parent()` to be set.
+    pub fn modal(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_MODAL as u32;
+        self
+    }
 }
 
 /// The type that allows you to build popup windows.
@@ -1604,6 +1889,17 @@ impl Window {
         self.into()
     }
 
+    /// Initializes a new `WindowCanvas` with the given output colorspace, e.g.
+    /// `Colorspace::SrgbLinear` for HDR output.
+    ///
+    /// See [`crate::render::create_renderer_with_output_colorspace`].
+    pub fn into_canvas_with_output_colorspace(
+        self,
+        colorspace: crate::pixels::Colorspace,
+    ) -> Result<WindowCanvas, IntegerOrSdlError> {
+        crate::render::create_renderer_with_output_colorspace(self, None, colorspace)
+    }
+
     pub fn context(&self) -> Arc<WindowContext> {
         self.context.clone()
     }
@@ -1789,6 +2085,14 @@ impl Window {
         unsafe { sys::video::SDL_GetWindowFlags(self.context.raw) }
     }
 
+    /// Returns the current window flags as a typed snapshot.
+    ///
+    /// Flags unknown to this crate are kept as-is.
+    #[doc(alias = "SDL_GetWindowFlags")]
+    pub fn flags(&self) -> WindowFlags {
+        WindowFlags::from_bits_retain(self.window_flags())
+    }
+
     /// Does the window have input focus?
     pub fn has_input_focus(&self) -> bool {
         0 != self.window_flags() & sys::video::SDL_WINDOW_INPUT_FOCUS as Uint64
@@ -1814,6 +2118,22 @@ impl Window {
         0 != self.window_flags() & sys::video::SDL_WINDOW_MINIMIZED as Uint64
     }
 
+    /// Is HDR rendering enabled for the window?
+    ///
+    /// There is no creation option for this: SDL enables HDR automatically
+    /// when the window is on an HDR display and HDR is enabled in the OS.
+    #[doc(alias = "SDL_PROP_WINDOW_HDR_ENABLED_BOOLEAN")]
+    pub fn is_hdr_enabled(&self) -> bool {
+        unsafe {
+            let props = sys::video::SDL_GetWindowProperties(self.context.raw);
+            sys::properties::SDL_GetBooleanProperty(
+                props,
+                sys::video::SDL_PROP_WINDOW_HDR_ENABLED_BOOLEAN,
+                false,
+            )
+        }
+    }
+
     #[doc(alias = "SDL_SetWindowTitle")]
     pub fn set_title(&mut self, title: &str) -> Result<(), NulError> {
         let title = CString::new(title)?;
@@ -1848,6 +2168,36 @@ impl Window {
         unsafe { sys::video::SDL_SetWindowIcon(self.context.raw, icon.as_ref().raw()) }
     }
 
+    /// Sets the shape of the window from the alpha channel of `shape`.
+    ///
+    /// Pixels with a non-zero alpha value are visible, the rest of the window
+    /// is see-through and does not receive input. The surface is scaled to the
+    /// current window size, and rescaled whenever the window is resized, so a
+    /// surface matching the window's pixel size gives a 1:1 mask. SDL keeps
+    /// its own copy, so `shape` may be dropped afterwards.
+    ///
+    /// The window must have been created with
+    /// [`WindowBuilder::transparent`]; otherwise an error is returned.
+    #[doc(alias = "SDL_SetWindowShape")]
+    pub fn set_shape<S: AsRef<SurfaceRef>>(&mut self, shape: S) -> Result<(), Error> {
+        if self.window_flags() & sys::video::SDL_WINDOW_TRANSPARENT == 0 {
+            return Err(Error(
+                "Window must be created with the transparent flag to set a shape".to_owned(),
+            ));
+        }
+        result_from_bool(unsafe {
+            sys::video::SDL_SetWindowShape(self.context.raw, shape.as_ref().raw())
+        })
+    }
+
+    /// Removes any shape previously set with [`Window::set_shape`].
+    #[doc(alias = "SDL_SetWindowShape")]
+    pub fn clear_shape(&mut self) -> Result<(), Error> {
+        result_from_bool(unsafe {
+            sys::video::SDL_SetWindowShape(self.context.raw, ptr::null_mut())
+        })
+    }
+
     //pub fn SDL_SetWindowData(window: *SDL_Window, name: *c_char, userdata: *c_void) -> *c_void; //TODO: Figure out what this does
     //pub fn SDL_GetWindowData(window: *SDL_Window, name: *c_char) -> *c_void;
 
@@ -1965,6 +2315,39 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Scale factors from window coordinates to pixels, per axis.
+    fn pixel_scale(&self) -> (f32, f32) {
+        let (w, h) = self.size();
+        let (pw, ph) = self.size_in_pixels();
+        let scale = |pixels: u32, size: u32| {
+            if size == 0 || pixels == 0 {
+                1.0
+            } else {
+                pixels as f32 / size as f32
+            }
+        };
+        (scale(pw, w), scale(ph, h))
+    }
+
+    /// Converts a point in window coordinates (as used by mouse and touch events) to pixel
+    /// coordinates in the window's drawable area.
+    ///
+    /// SDL has no dedicated function for this; the conversion uses the ratio of
+    /// [`Window::size_in_pixels`] to [`Window::size`], which equals [`Window::pixel_density`]
+    /// and may be fractional (e.g. 1.5 with fractional scaling). It is only valid for the current
+    /// window size, so convert again after a resize or a pixel size change event.
+    pub fn pixels_from_coordinates(&self, x: f32, y: f32) -> (f32, f32) {
+        let (sx, sy) = self.pixel_scale();
+        (x * sx, y * sy)
+    }
+
+    /// Converts a point in pixel coordinates to window coordinates; the inverse of
+    /// [`Window::pixels_from_coordinates`].
+    pub fn coordinates_from_pixels(&self, x: f32, y: f32) -> (f32, f32) {
+        let (sx, sy) = self.pixel_scale();
+        (x / sx, y / sy)
+    }
+
     #[doc(alias = "SDL_SetWindowMinimumSize")]
     pub fn set_minimum_size(&mut self, width: u32, height: u32) -> Result<(), IntegerOrSdlError> {
         let w = validate_int(width, "width")?;
@@ -2001,36 +2384,110 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Constrains the aspect ratio (width / height) of the window's client area
+    /// while it is being resized.
+    ///
+    /// Passing the same value for `min_aspect` and `max_aspect` locks the window
+    /// to a single ratio, and `0.0` for either bound removes that limit.
+    #[doc(alias = "SDL_SetWindowAspectRatio")]
+    pub fn set_aspect_ratio(&mut self, min_aspect: f32, max_aspect: f32) -> Result<(), Error> {
+        result_from_bool(unsafe {
+            sys::video::SDL_SetWindowAspectRatio(self.context.raw, min_aspect, max_aspect)
+        })
+    }
+
+    /// Returns the `(min_aspect, max_aspect)` aspect ratio constraints of the window.
+    #[doc(alias = "SDL_GetWindowAspectRatio")]
+    pub fn aspect_ratio(&self) -> Result<(f32, f32), Error> {
+        let mut min_aspect = 0.0;
+        let mut max_aspect = 0.0;
+        result_from_bool(unsafe {
+            sys::video::SDL_GetWindowAspectRatio(self.context.raw, &mut min_aspect, &mut max_aspect)
+        })?;
+        Ok((min_aspect, max_aspect))
+    }
+
+    /// Adds or removes the window decorations (title bar, borders).
     #[doc(alias = "SDL_SetWindowBordered")]
     pub fn set_bordered(&mut self, bordered: bool) -> bool {
         unsafe { sys::video::SDL_SetWindowBordered(self.context.raw, bordered) }
     }
 
+    /// Allows or disallows resizing the window by the user.
+    #[doc(alias = "SDL_SetWindowResizable")]
+    pub fn set_resizable(&mut self, resizable: bool) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_SetWindowResizable(self.context.raw, resizable) })
+    }
+
+    /// Keeps the window above all other windows, or releases it.
+    #[doc(alias = "SDL_SetWindowAlwaysOnTop")]
+    pub fn set_always_on_top(&mut self, on_top: bool) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_SetWindowAlwaysOnTop(self.context.raw, on_top) })
+    }
+
+    /// Shows the window.
+    ///
+    /// Returns `false` on failure; call [`get_error`] for more information.
     #[doc(alias = "SDL_ShowWindow")]
     pub fn show(&mut self) -> bool {
         unsafe { sys::video::SDL_ShowWindow(self.context.raw) }
     }
 
+    /// Hides the window.
+    ///
+    /// Returns `false` on failure; call [`get_error`] for more information.
     #[doc(alias = "SDL_HideWindow")]
     pub fn hide(&mut self) -> bool {
         unsafe { sys::video::SDL_HideWindow(self.context.raw) }
     }
 
+    /// Raises the window above other windows and requests input focus.
+    ///
+    /// This is a request; window managers may refuse to steal focus from
+    /// another application, e.g. by flashing the taskbar entry instead.
+    ///
+    /// Returns `false` on failure; call [`get_error`] for more information.
     #[doc(alias = "SDL_RaiseWindow")]
     pub fn raise(&mut self) -> bool {
         unsafe { sys::video::SDL_RaiseWindow(self.context.raw) }
     }
 
+    /// Requests that the window be made as large as possible.
+    ///
+    /// Non-resizable windows can't be maximized. On some platforms
+    /// (notably Wayland and some X11 window managers) this is advisory and
+    /// may be ignored or applied asynchronously; wait for a
+    /// [`WindowEvent::Maximized`](crate::event::WindowEvent::Maximized) event
+    /// to know when the state actually changed.
+    ///
+    /// Returns `false` on failure; call [`get_error`] for more information.
     #[doc(alias = "SDL_MaximizeWindow")]
     pub fn maximize(&mut self) -> bool {
         unsafe { sys::video::SDL_MaximizeWindow(self.context.raw) }
     }
 
+    /// Requests that the window be minimized to an iconic representation.
+    ///
+    /// On some platforms this is advisory and may be ignored or applied
+    /// asynchronously; wait for a
+    /// [`WindowEvent::Minimized`](crate::event::WindowEvent::Minimized) event
+    /// to know when the state actually changed.
+    ///
+    /// Returns `false` on failure; call [`get_error`] for more information.
     #[doc(alias = "SDL_MinimizeWindow")]
     pub fn minimize(&mut self) -> bool {
         unsafe { sys::video::SDL_MinimizeWindow(self.context.raw) }
     }
 
+    /// Requests that the size and position of a minimized or maximized window
+    /// be restored.
+    ///
+    /// On some platforms this is advisory and may be applied asynchronously;
+    /// wait for a
+    /// [`WindowEvent::Restored`](crate::event::WindowEvent::Restored) event
+    /// to know when the state actually changed.
+    ///
+    /// Returns `false` on failure; call [`get_error`] for more information.
     #[doc(alias = "SDL_RestoreWindow")]
     pub fn restore(&mut self) -> bool {
         unsafe { sys::video::SDL_RestoreWindow(self.context.raw) }
@@ -2096,6 +2553,25 @@ impl Window {
         unsafe { sys::video::SDL_GetWindowMouseGrab(self.context.raw) }
     }
 
+    /// Checks whether text input events are enabled for this window.
+    ///
+    /// Text input is started and stopped with `TextInputUtil::start` and
+    /// `TextInputUtil::stop`.
+    #[doc(alias = "SDL_TextInputActive")]
+    pub fn text_input_active(&self) -> bool {
+        unsafe { sys::keyboard::SDL_TextInputActive(self.context.raw) }
+    }
+
+    /// Checks whether the on-screen keyboard is currently shown for this
+    /// window.
+    ///
+    /// Use `TextInputUtil::has_screen_keyboard_support` to find out if the
+    /// platform has an on-screen keyboard at all.
+    #[doc(alias = "SDL_ScreenKeyboardShown")]
+    pub fn screen_keyboard_shown(&self) -> bool {
+        unsafe { sys::keyboard::SDL_ScreenKeyboardShown(self.context.raw) }
+    }
+
     #[doc(alias = "SDL_SetWindowMouseRect")]
     pub fn set_mouse_rect<R>(&self, rect: R) -> Result<(), Error>
     where
@@ -2172,6 +2648,55 @@ impl Window {
             Err(get_error())
         }
     }
+
+    /// Sets or clears the parent of this window.
+    ///
+    /// A child window moves with its parent and is always kept above it.
+    /// Passing `None` detaches the window from its current parent.
+    ///
+    /// The parent is kept alive for as long as it is set here, because SDL
+    /// destroys child windows together with their parent. A modal window must
+    /// have its modal state cleared before its parent can be changed.
+    #[doc(alias = "SDL_SetWindowParent")]
+    pub fn set_parent(&mut self, parent: Option<&Window>) -> Result<(), Error> {
+        result_from_bool(unsafe {
+            sys::video::SDL_SetWindowParent(
+                self.context.raw,
+                parent.map_or(null_mut(), |parent| parent.raw()),
+            )
+        })?;
+        *self.context.parent.lock().unwrap() = parent.map(|parent| parent.context());
+        Ok(())
+    }
+
+    /// Sets whether leaving relative mouse mode puts the cursor back where it was when relative
+    /// mode was entered.
+    ///
+    /// By default SDL leaves the cursor at the position it tracked while in relative mode
+    /// (usually the window center). With this enabled,
+    /// [`MouseUtil::set_relative_mouse_mode`](crate::mouse::MouseUtil::set_relative_mouse_mode)
+    /// saves the cursor position when turning relative mode on and warps back to it when
+    /// turning it off. Motion events report `xrel`/`yrel` as usual in both cases.
+    pub fn set_relative_mouse_warp(&mut self, enabled: bool) {
+        let mut restore = self.context.relative_mouse_restore.lock().unwrap();
+        restore.enabled = enabled;
+        if !enabled {
+            restore.position = None;
+        }
+    }
+
+    /// Toggles the modal state of this window.
+    ///
+    /// A modal window blocks input to its parent until it is closed or made
+    /// non-modal. The window must have a parent set with `set_parent`,
+    /// otherwise this fails.
+    ///
+    /// How strictly modality is enforced depends on the platform: some window
+    /// managers (notably on X11 and Wayland) treat it only as a hint.
+    #[doc(alias = "SDL_SetWindowModal")]
+    pub fn set_modal(&mut self, modal: bool) -> Result<(), Error> {
+        result_from_bool(unsafe { sys::video::SDL_SetWindowModal(self.context.raw, modal) })
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -2210,12 +2735,15 @@ impl Iterator for DriverIterator {
 
 impl ExactSizeIterator for DriverIterator {}
 
-/// Gets an iterator of all video drivers compiled into the SDL2 library.
+/// Gets an iterator of all video drivers compiled into the SDL library.
+///
+/// Not every listed driver is necessarily usable at runtime (e.g. `"wayland"`
+/// without a compositor).
 #[inline]
 #[doc(alias = "SDL_GetVideoDriver")]
 pub fn drivers() -> DriverIterator {
     // This function is thread-safe and doesn't require the video subsystem to be initialized.
-    // The list of drivers are read-only and statically compiled into SDL2, varying by platform.
+    // The list of drivers are read-only and statically compiled into SDL, varying by platform.
 
     // SDL_GetNumVideoDrivers can never return a negative value.
     DriverIterator {
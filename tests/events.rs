@@ -145,3 +145,31 @@ fn test_event_sender_no_subsystem() {
         })
         .is_err());
 }
+
+#[test]
+fn test_poll_batch() {
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let mut ep = sdl.event_pump().unwrap();
+
+    // drop anything SDL queued during initialization
+    ep.poll_batch(0);
+
+    let user_event_id = unsafe { ev.register_event().unwrap() };
+    for code in 0..3 {
+        ev.push_event(event::Event::User {
+            timestamp: 0,
+            window_id: 0,
+            type_: user_event_id,
+            code,
+            data1: ::std::ptr::null_mut(),
+            data2: ::std::ptr::null_mut(),
+        })
+        .unwrap();
+    }
+
+    assert_eq!(ep.poll_batch(2).len(), 2);
+    assert_eq!(ep.poll_batch(0).len(), 1);
+    assert!(ep.poll_batch(0).is_empty());
+}